@@ -0,0 +1,60 @@
+use std::cmp::{Ordering, Reverse};
+
+/// Defines the order in which a SortedList keeps its elements.
+///
+/// It is implemented by [`Natural`], which follows `Ord`, by `Reverse<C>`, which reverses
+/// the order of another comparator, and by every closure `Fn(&T, &T) -> Ordering`.
+/// A comparator must define a total order, and must not change its ordering while in use.
+///
+/// # Example
+///
+/// ```
+/// use std::cmp::Reverse;
+/// use sortedlist_rs::{Natural, SortedList};
+///
+/// let mut descending = SortedList::with_comparator(Reverse(Natural));
+/// descending.extend([10, 2, 99]);
+/// assert_eq!(vec![99, 10, 2], descending.to_vec());
+///
+/// let mut case_insensitive =
+///     SortedList::with_comparator(|a: &&str, b: &&str| a.to_lowercase().cmp(&b.to_lowercase()));
+/// case_insensitive.extend(["b", "C", "a"]);
+/// assert_eq!(vec!["a", "b", "C"], case_insensitive.to_vec());
+/// ```
+pub trait Comparator<T: ?Sized> {
+    /// Returns the ordering of `a` relative to `b`.
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// The natural order of `Ord` types. It is the default comparator of a SortedList.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Natural;
+
+impl<T> Comparator<T> for Natural
+where
+    T: Ord + ?Sized,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl<T, C> Comparator<T> for Reverse<C>
+where
+    T: ?Sized,
+    C: Comparator<T>,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.0.compare(a, b).reverse()
+    }
+}
+
+impl<T, F> Comparator<T> for F
+where
+    T: ?Sized,
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
@@ -1,5 +1,24 @@
 use core::fmt;
-use std::{fmt::Debug, ops::Index, vec::IntoIter};
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::Debug,
+    iter::{FusedIterator, Peekable},
+    ops::{Bound, Index, Range, RangeBounds},
+    slice, vec,
+};
+
+mod comparator;
+mod sorted_dict;
+mod sorted_key_list;
+mod sorted_multimap;
+mod sorted_set;
+
+pub use comparator::{Comparator, Natural};
+pub use sorted_dict::{Entry, OccupiedEntry, SortedDict, VacantEntry};
+pub use sorted_key_list::SortedKeyList;
+pub use sorted_multimap::SortedMultiMap;
+pub use sorted_set::SortedSet;
 
 /// A sorted list data structure
 ///
@@ -31,10 +50,7 @@ use std::{fmt::Debug, ops::Index, vec::IntoIter};
 /// println!("{:?}", sorted_list);
 /// // [1, 19, 20, 90, 100]
 /// ```
-pub struct SortedList<T>
-where
-    T: Ord,
-{
+pub struct SortedList<T, C = Natural> {
     _lists: Vec<Vec<T>>,
     _index_tree: Vec<usize>,
     _index_tree_offset: usize,
@@ -42,20 +58,21 @@ where
     _upper_load_factor: usize,
     _lower_load_factor: usize,
     _len: usize,
+    _cmp: C,
 }
 
 /// Private method implementations
-impl<T> SortedList<T>
+impl<T, C> SortedList<T, C>
 where
-    T: Ord,
+    C: Comparator<T>,
 {
     const DEFAULT_INDEX_TREE_OFFSET: usize = 1 << 5;
     const DEFAULT_LOAD_FACTOR: usize = 1_024;
     const DEFAULT_UPPER_LOAD_FACTOR: usize = 2_048;
     const DEFAULT_LOWER_LOAD_FACTOR: usize = 512;
 
-    /// Instantiate an empty SortedList.
-    fn _default() -> Self {
+    /// Instantiate an empty SortedList ordered by `cmp`.
+    fn _with_comparator(cmp: C) -> Self {
         Self {
             _lists: vec![],
             _index_tree: vec![0; 2 * Self::DEFAULT_INDEX_TREE_OFFSET],
@@ -64,9 +81,21 @@ where
             _upper_load_factor: Self::DEFAULT_UPPER_LOAD_FACTOR,
             _lower_load_factor: Self::DEFAULT_LOWER_LOAD_FACTOR,
             _len: 0,
+            _cmp: cmp,
         }
     }
 
+    /// Instantiate a SortedList ordered by `cmp` from `sorted`, which must already be sorted by `cmp`.
+    fn _from_sorted(sorted: Vec<T>, cmp: C) -> Self {
+        let mut sorted_list = Self {
+            _len: sorted.len(),
+            _lists: vec![sorted],
+            ..Self::_with_comparator(cmp)
+        };
+        sorted_list._rebalance();
+        sorted_list
+    }
+
     /// Collapse self._lists\[i]. self._lists\[i].len() must be > 1.
     fn _collapse(&mut self, i: usize) {
         if self._lists.len() <= 1 {
@@ -198,36 +227,260 @@ where
         removed
     }
 
+    /// Remove the elements stored within positions \[(i0,j0), (i1,j1)) of self._lists.
+    /// Returns the removed elements in order, as a list of lists. Lists fully covered by the range are moved out whole.
+    fn _lists_remove_range(&mut self, start: (usize, usize), end: (usize, usize)) -> Vec<Vec<T>> {
+        let ((i0, j0), (i1, j1)) = (start, end);
+
+        if i0 == i1 {
+            let removed: Vec<T> = self._lists[i0].drain(j0..j1).collect();
+            self._len -= removed.len();
+
+            if self._lists.len() > 1 && self._lists[i0].len() < self._lower_load_factor {
+                self._rebalance();
+            } else {
+                self._index_tree_add(i0, -(removed.len() as i32));
+            }
+            return vec![removed];
+        }
+
+        let mut removed: Vec<Vec<T>> = Vec::with_capacity(i1 - i0 + 1);
+        removed.push(match j0 {
+            0 => std::mem::take(&mut self._lists[i0]),
+            _ => self._lists[i0].drain(j0..).collect(),
+        });
+        removed.extend(self._lists.drain(i0 + 1..i1));
+        // self._lists[i1] has been shifted to self._lists[i0 + 1]
+        removed.push(match j1 == self._lists[i0 + 1].len() {
+            true => std::mem::take(&mut self._lists[i0 + 1]),
+            false => self._lists[i0 + 1].drain(..j1).collect(),
+        });
+        self._len -= removed.iter().map(Vec::len).sum::<usize>();
+
+        self._rebalance();
+        removed
+    }
+
+    /// Split self._lists at position (i,j) and move the elements from self._lists\[i]\[j] onwards to a new SortedList.
+    /// `len` is the number of elements before position (i,j).
+    fn _lists_split_off(&mut self, (i, j): (usize, usize), len: usize) -> Self
+    where
+        C: Clone,
+    {
+        let mut lists = self._lists.split_off(i + 1);
+        lists.insert(0, self._lists[i].split_off(j));
+
+        let mut other = Self {
+            _lists: lists,
+            _len: self._len - len,
+            ..Self::_with_comparator(self._cmp.clone())
+        };
+        self._len = len;
+
+        self._rebalance();
+        other._rebalance();
+        other
+    }
+
+    /// Restore the load factor bounds of every list in self._lists after a bulk update,
+    /// dropping empty lists, then rebuild the index segment tree once.
+    fn _rebalance(&mut self) {
+        let lists = std::mem::take(&mut self._lists);
+        let mut balanced: Vec<Vec<T>> = Vec::with_capacity(lists.len());
+
+        for mut list in lists {
+            if list.is_empty() {
+                continue;
+            }
+
+            match balanced.last_mut() {
+                Some(last)
+                    if last.len() < self._lower_load_factor
+                        || list.len() < self._lower_load_factor =>
+                {
+                    last.append(&mut list);
+                    let last = balanced.pop().unwrap();
+                    self._push_balanced(&mut balanced, last);
+                }
+                _ => self._push_balanced(&mut balanced, list),
+            }
+        }
+
+        self._lists = balanced;
+        self._rebuild_index_tree();
+    }
+
+    /// Push `list` onto `lists`, splitting it into evenly sized lists of about self._load_factor elements
+    /// if it exceeds self._upper_load_factor.
+    fn _push_balanced(&self, lists: &mut Vec<Vec<T>>, list: Vec<T>) {
+        if list.len() <= self._upper_load_factor {
+            lists.push(list);
+            return;
+        }
+
+        let pieces = list.len().div_ceil(self._load_factor);
+        let (size, remainder) = (list.len() / pieces, list.len() % pieces);
+        let mut iter = list.into_iter();
+        for piece in 0..pieces {
+            let piece_size = size + usize::from(piece < remainder);
+            lists.push(iter.by_ref().take(piece_size).collect());
+        }
+    }
+
+    /// Merge the sorted `batch` into self._lists, touching only the lists which receive new elements,
+    /// then rebuild the index segment tree once.
+    fn _lists_insert_sorted(&mut self, batch: Vec<T>) {
+        if batch.is_empty() {
+            return;
+        }
+
+        if self._len == 0 {
+            self._lists.clear();
+            self._lists.push(vec![]);
+        }
+        self._len += batch.len();
+
+        let mut lists = std::mem::take(&mut self._lists).into_iter().peekable();
+        let mut batch = batch.into_iter().peekable();
+        let mut merged_lists: Vec<Vec<T>> = Vec::with_capacity(lists.len());
+
+        while let Some(list) = lists.next() {
+            // same rule as _bisect_right_lists: elements go to the last list whose first element is not greater
+            let incoming: Vec<T> = match lists.peek() {
+                Some(next) => {
+                    let mut incoming = vec![];
+                    while let Some(element) = batch
+                        .next_if(|element| self._cmp.compare(element, &next[0]) == Ordering::Less)
+                    {
+                        incoming.push(element);
+                    }
+                    incoming
+                }
+                None => batch.by_ref().collect(),
+            };
+
+            match incoming.is_empty() {
+                true => merged_lists.push(list),
+                false => {
+                    let merged = self._merge_sorted(list, incoming);
+                    self._push_balanced(&mut merged_lists, merged)
+                }
+            }
+        }
+
+        self._lists = merged_lists;
+        self._rebuild_index_tree();
+    }
+
+    /// Merge two sorted Vecs into a single sorted Vec.
+    fn _merge_sorted(&self, a: Vec<T>, b: Vec<T>) -> Vec<T> {
+        let mut merged = Vec::with_capacity(a.len() + b.len());
+        Self::_merge_sorted_with(&self._cmp, a.into_iter(), b.into_iter(), |element| {
+            merged.push(element)
+        });
+        merged
+    }
+
+    /// Merge two iterators sorted by `cmp`, feeding every element to `push` in sorted order.
+    fn _merge_sorted_with<A, B, F>(cmp: &C, a: A, b: B, mut push: F)
+    where
+        A: Iterator<Item = T>,
+        B: Iterator<Item = T>,
+        F: FnMut(T),
+    {
+        let mut a = a.peekable();
+        let mut b = b.peekable();
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            match cmp.compare(y, x) == Ordering::Less {
+                true => push(b.next().unwrap()),
+                false => push(a.next().unwrap()),
+            }
+        }
+
+        a.for_each(&mut push);
+        b.for_each(&mut push);
+    }
+
+    /// Removes consecutive elements for which `same(cmp, element, previous)` holds, across list boundaries.
+    fn _dedup_by<F>(&mut self, mut same: F)
+    where
+        F: FnMut(&C, &T, &T) -> bool,
+    {
+        let len = self._len;
+        let mut last_kept: Option<usize> = None;
+        let cmp = &self._cmp;
+        let lists = &mut self._lists;
+        self._len = 0;
+
+        for i in 0..lists.len() {
+            lists[i].dedup_by(|a, b| same(cmp, a, b));
+
+            // the run of the last kept element may continue at the front of self._lists[i]
+            if let Some(p) = last_kept {
+                let (prev, rest) = lists.split_at_mut(i);
+                let last = prev[p].last().unwrap();
+                let n = rest[0].iter().take_while(|x| same(cmp, x, last)).count();
+                rest[0].drain(..n);
+            }
+
+            if !lists[i].is_empty() {
+                last_kept = Some(i);
+            }
+            self._len += lists[i].len();
+        }
+
+        if self._len != len {
+            self._rebalance();
+        }
+    }
+
     /// Insert `element` into self._lists\[i]. It is assumed that self._lists\[i] is the correct insert position.
     fn _lists_insert(&mut self, i: usize, element: T) {
         // insert ele into self._lists[i]
         // assumptions:
         // 1. self._lists[i] must exist
         // 2. i is the correct position for inserting ele
-        let pos = match self._lists[i].binary_search(&element) {
-            Ok(p) => p,
-            Err(p) => p,
-        };
+        // insert after the elements equal to ele, so that equal elements keep their insertion order
+        let pos =
+            self._lists[i].partition_point(|x| self._cmp.compare(x, &element) != Ordering::Greater);
+        self._lists_insert_at(i, pos, element);
+    }
 
-        self._lists[i].insert(pos, element);
+    /// Insert `element` at self._lists\[i]\[j]. It is assumed that (i,j) is the correct insert position.
+    /// Returns the position of `element` afterwards, as self._lists\[i] may have been split.
+    fn _lists_insert_at(&mut self, i: usize, j: usize, element: T) -> (usize, usize) {
+        self._lists[i].insert(j, element);
         self._len += 1;
 
-        if self._lists[i].len() > self._upper_load_factor {
+        let size = self._lists[i].len();
+        if size > self._upper_load_factor {
+            // _expand moves self._lists[i][size/2..] into a new self._lists[i+1]
             self._expand(i);
-        } else {
-            self._index_tree_add(i, 1);
+            return match j < size / 2 {
+                true => (i, j),
+                false => (i + 1, j - size / 2),
+            };
         }
+
+        self._index_tree_add(i, 1);
+        (i, j)
     }
 
     /// Find the position in self._lists which element should be inserted.
-    fn _bisect_right_lists(&self, element: &T) -> usize {
-        if &self._lists[0][0] > element {
+    fn _bisect_right_lists<Q>(&self, element: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        if self._cmp.compare(self._lists[0][0].borrow(), element) == Ordering::Greater {
             return 0;
         }
 
         let mut lo = 0;
         let mut hi = self._lists.len() - 1;
-        if &self._lists[hi][0] <= element {
+        if self._cmp.compare(self._lists[hi][0].borrow(), element) != Ordering::Greater {
             return hi;
         }
 
@@ -236,7 +489,7 @@ where
         let mut mid;
         while lo + 1 < hi {
             mid = (lo + hi) / 2;
-            if &self._lists[mid][0] <= element {
+            if self._cmp.compare(self._lists[mid][0].borrow(), element) != Ordering::Greater {
                 lo = mid;
             } else {
                 hi = mid;
@@ -246,6 +499,223 @@ where
         lo
     }
 
+    /// Find the first position in self._lists whose last element is not less than `element`.
+    /// Falls back to the last position if every element is less than `element`.
+    fn _bisect_left_lists<Q>(&self, element: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        let i = self._lists.partition_point(|list| {
+            self._cmp.compare(list.last().unwrap().borrow(), element) == Ordering::Less
+        });
+        i.min(self._lists.len() - 1)
+    }
+
+    /// Returns the number of elements stored in self._lists\[..i].
+    fn _lists_offset(&self, i: usize) -> usize {
+        match i {
+            0 => 0,
+            _ => self._index_tree_sum(0, i - 1, None, None, None),
+        }
+    }
+
+    /// Move a past-the-end position of self._lists\[i] to the front of self._lists\[i+1], if it exists.
+    fn _normalize_position(&self, i: usize, j: usize) -> (usize, usize) {
+        match j == self._lists[i].len() && i + 1 < self._lists.len() {
+            true => (i + 1, 0),
+            false => (i, j),
+        }
+    }
+
+    /// Returns (i,j) such that self._lists\[i]\[j] is the first element not less than `element`.
+    fn _bisect_left_position<Q>(&self, element: &Q) -> (usize, usize)
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        let i = self._bisect_left_lists(element);
+        let j = self._lists[i]
+            .partition_point(|x| self._cmp.compare(x.borrow(), element) == Ordering::Less);
+        self._normalize_position(i, j)
+    }
+
+    /// Returns (i,j) such that self._lists\[i]\[j] is the first element greater than `element`.
+    fn _bisect_right_position<Q>(&self, element: &Q) -> (usize, usize)
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        let i = self._bisect_right_lists(element);
+        let j = self._lists[i]
+            .partition_point(|x| self._cmp.compare(x.borrow(), element) != Ordering::Greater);
+        self._normalize_position(i, j)
+    }
+
+    /// Returns (i,j) such that self._lists\[i]\[j] is the k-th element (0-indexed) of the SortedList,
+    /// or the past-the-end position of the last list if k == self._len.
+    fn _position_of_rank(&self, k: usize) -> (usize, usize) {
+        match k == self._len {
+            true => (self._lists.len() - 1, self._lists.last().unwrap().len()),
+            false => self._locate_kth_element(k),
+        }
+    }
+
+    /// Resolve the index range `range` into a `Range<usize>`. Panics if it goes out of bound.
+    fn _resolve_index_range<R>(&self, range: &R) -> Range<usize>
+    where
+        R: RangeBounds<usize>,
+    {
+        // a bound of usize::MAX cannot be made exclusive, and is out of range anyway
+        let start = match range.start_bound() {
+            Bound::Included(&k) => k,
+            Bound::Excluded(&k) => k.checked_add(1).expect("SortedList: Index out of range."),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&k) => k.checked_add(1).expect("SortedList: Index out of range."),
+            Bound::Excluded(&k) => k,
+            Bound::Unbounded => self._len,
+        };
+
+        if start > end || end > self._len {
+            panic!(
+                "SortedList: Index range {}..{} out of range for length {}.",
+                start, end, self._len
+            );
+        }
+
+        start..end
+    }
+
+    /// Returns an iterator over the elements stored within positions \[(i0,j0), (i1,j1)) of self._lists.
+    fn _iter_positions(&self, positions: Option<((usize, usize), (usize, usize))>) -> Iter<'_, T> {
+        let ((i0, j0), (i1, j1)) = match positions {
+            Some(positions) => positions,
+            None => return Iter::_new(&[], [].iter(), [].iter(), 0),
+        };
+
+        let len = (self._lists_offset(i1) + j1) - (self._lists_offset(i0) + j0);
+        if i0 == i1 {
+            return Iter::_new(&[], self._lists[i0][j0..j1].iter(), [].iter(), len);
+        }
+
+        Iter::_new(
+            &self._lists[i0 + 1..i1],
+            self._lists[i0][j0..].iter(),
+            self._lists[i1][..j1].iter(),
+            len,
+        )
+    }
+
+    /// Returns the position right before (i,j) in self._lists, if any.
+    fn _position_before(&self, i: usize, j: usize) -> Option<(usize, usize)> {
+        match (i, j) {
+            (0, 0) => None,
+            (_, 0) => Some((i - 1, self._lists[i - 1].len() - 1)),
+            _ => Some((i, j - 1)),
+        }
+    }
+
+    /// Returns (i,j) if it points at an element of self._lists.
+    fn _position_at(&self, i: usize, j: usize) -> Option<(usize, usize)> {
+        match j < self._lists[i].len() {
+            true => Some((i, j)),
+            false => None,
+        }
+    }
+
+    /// Returns the position of the last element not greater than `element`.
+    fn _floor_position<Q>(&self, element: &Q) -> Option<(usize, usize)>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        if self._len == 0 {
+            return None;
+        }
+        let (i, j) = self._bisect_right_position(element);
+        self._position_before(i, j)
+    }
+
+    /// Returns the position of the first element not less than `element`.
+    fn _ceiling_position<Q>(&self, element: &Q) -> Option<(usize, usize)>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        if self._len == 0 {
+            return None;
+        }
+        let (i, j) = self._bisect_left_position(element);
+        self._position_at(i, j)
+    }
+
+    /// Returns the position of the last element less than `element`.
+    fn _lower_position<Q>(&self, element: &Q) -> Option<(usize, usize)>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        if self._len == 0 {
+            return None;
+        }
+        let (i, j) = self._bisect_left_position(element);
+        self._position_before(i, j)
+    }
+
+    /// Returns the position of the first element greater than `element`.
+    fn _higher_position<Q>(&self, element: &Q) -> Option<(usize, usize)>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        if self._len == 0 {
+            return None;
+        }
+        let (i, j) = self._bisect_right_position(element);
+        self._position_at(i, j)
+    }
+
+    /// Resolve the value range `range` into half-open positions \[(i0,j0), (i1,j1)) of self._lists.
+    /// Returns None if no element falls within `range`.
+    fn _range_positions<Q, R>(&self, range: &R) -> Option<((usize, usize), (usize, usize))>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+        R: RangeBounds<Q>,
+    {
+        if self._len == 0 {
+            return None;
+        }
+
+        let start = match range.start_bound() {
+            Bound::Included(element) => self._bisect_left_position(element),
+            Bound::Excluded(element) => self._bisect_right_position(element),
+            Bound::Unbounded => (0, 0),
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(element) => self._bisect_right_position(element),
+            Bound::Excluded(element) => self._bisect_left_position(element),
+            Bound::Unbounded => (self._lists.len() - 1, self._lists.last().unwrap().len()),
+        };
+
+        match start < end {
+            true => Some((start, end)),
+            false => None,
+        }
+    }
+
     /// Returns (i,j) such that self._lists\[i]\[j] is the k-th element (0-indexed) of the SortedList.
     fn _locate_kth_element(&self, k: usize) -> (usize, usize) {
         // input k is 0-indexed
@@ -286,7 +756,7 @@ where
     }
 }
 
-/// Public method implementations
+/// Natural order method implementations
 impl<T> SortedList<T>
 where
     T: Ord,
@@ -301,7 +771,39 @@ where
     /// let sorted_list: SortedList<i32> = SortedList::new();
     /// ```
     pub fn new() -> Self {
-        Self::_default()
+        Self::_with_comparator(Natural)
+    }
+}
+
+/// Public method implementations
+impl<T, C> SortedList<T, C>
+where
+    C: Comparator<T>,
+{
+    /// Creates an empty SortedList ordered by the comparator `cmp`, chosen at runtime if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let descending = true;
+    /// let mut sorted_list = SortedList::with_comparator(move |a: &i32, b: &i32| match descending {
+    ///     true => b.cmp(a),
+    ///     false => a.cmp(b),
+    /// });
+    /// sorted_list.extend([10, 2, 99]);
+    ///
+    /// assert_eq!(vec![99, 10, 2], sorted_list.to_vec());
+    /// assert_eq!(Ok(1), sorted_list.binary_search(&10));
+    /// ```
+    pub fn with_comparator(cmp: C) -> Self {
+        Self::_with_comparator(cmp)
+    }
+
+    /// Returns a reference to the comparator of the SortedList.
+    pub fn comparator(&self) -> &C {
+        &self._cmp
     }
 
     /// Find the k-th smallest (0-indexed) element in the SortedList.
@@ -317,7 +819,29 @@ where
     pub fn kth_smallest(&self, k: usize) -> &T {
         // k is 0-indexed
         let (i, j) = self._locate_kth_element(k);
-        return self._at(i, j);
+        self._at(i, j)
+    }
+
+    /// Find the k-th largest (0-indexed) element in the SortedList.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 3]);
+    /// assert_eq!(&10, sorted_list.kth_largest(0));
+    /// assert_eq!(&3, sorted_list.kth_largest(1));
+    /// ```
+    pub fn kth_largest(&self, k: usize) -> &T {
+        match self.get_from_end(k) {
+            Some(element) => element,
+            None => panic!("SortedList: Index out of range."),
+        }
     }
 
     /// Clears the SortedList.
@@ -342,7 +866,7 @@ where
         self._len = 0;
     }
 
-    /// Insert `element` into the SortedList.
+    /// Insert `element` into the SortedList, after the elements equal to it.
     ///
     /// # Example
     ///
@@ -387,94 +911,1123 @@ where
         self._lists_remove(i, j)
     }
 
-    /// Binary searches the given element in the SortedList.
-    /// Returns Ok(i) for exact match, Err(i) otherwise.
+    /// Removes the first element of the SortedList, i.e. the smallest element, and returns it.
+    /// Returns None if the SortedList is empty.
     ///
     /// # Example
     ///
     /// ```
     /// use sortedlist_rs::SortedList;
     ///
-    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 30]);
-    ///
-    /// let result = sorted_list.binary_search(&30);
-    /// assert_eq!(Ok(3), result);
+    /// let mut sorted_list = SortedList::from([10, 2, 99]);
     ///
-    /// let result = sorted_list.binary_search(&90);
-    /// assert_eq!(Err(4), result);
+    /// assert_eq!(Some(2), sorted_list.pop_first());
+    /// assert_eq!(Some(10), sorted_list.pop_first());
+    /// assert_eq!(Some(99), sorted_list.pop_first());
+    /// assert_eq!(None, sorted_list.pop_first());
     /// ```
-    pub fn binary_search(&self, element: &T) -> Result<usize, usize> {
-        if self._len == 0 {
-            return Err(0);
-        }
-
-        let i: usize = self._bisect_right_lists(element);
-        if i == 0 {
-            return self._lists[i].binary_search(element);
-        }
-
-        match self._lists[i].binary_search(element) {
-            Ok(pos) => Ok(pos + self._index_tree_sum(0, i - 1, None, None, None)),
-            Err(pos) => Err(pos + self._index_tree_sum(0, i - 1, None, None, None)),
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
         }
+        Some(self._lists_remove(0, 0))
     }
 
-    /// Returns whether the SortedList contains a specific element.
+    /// Removes the last element of the SortedList, i.e. the largest element, and returns it.
+    /// Returns None if the SortedList is empty.
     ///
     /// # Example
     ///
     /// ```
     /// use sortedlist_rs::SortedList;
     ///
-    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    /// let mut sorted_list = SortedList::from([10, 2, 99]);
     ///
-    /// assert_eq!(true, sorted_list.contains(&10));
-    /// assert_eq!(false, sorted_list.contains(&90));
+    /// assert_eq!(Some(99), sorted_list.pop_last());
+    /// assert_eq!(Some(10), sorted_list.pop_last());
+    /// assert_eq!(Some(2), sorted_list.pop_last());
+    /// assert_eq!(None, sorted_list.pop_last());
     /// ```
-    pub fn contains(&self, element: &T) -> bool {
-        self.binary_search(element).is_ok()
+    pub fn pop_last(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let i = self._lists.len() - 1;
+        let j = self._lists[i].len() - 1;
+        Some(self._lists_remove(i, j))
     }
 
-    /// Returns the number of elements stored in the SortedList.
+    /// Removes one element equal to `element` from the SortedList and returns it, if any.
     ///
     /// # Example
     ///
     /// ```
     /// use sortedlist_rs::SortedList;
     ///
-    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    /// let mut sorted_list = SortedList::from([10, 20, 20, 30]);
     ///
-    /// assert_eq!(4, sorted_list.len());
+    /// assert_eq!(Some(20), sorted_list.remove_value(&20));
+    /// assert_eq!(None, sorted_list.remove_value(&25));
+    /// assert_eq!(3, sorted_list.len());
     /// ```
-    pub fn len(&self) -> usize {
-        self._len
+    pub fn remove_value<Q>(&mut self, element: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        let (i, j) = self._ceiling_position(element)?;
+        match self._cmp.compare(self._at(i, j).borrow(), element) == Ordering::Equal {
+            true => Some(self._lists_remove(i, j)),
+            false => None,
+        }
     }
 
-    /// Returns whether the SortedList is empty.
+    /// Removes one element equal to `element` from the SortedList.
+    /// Returns whether such an element was found.
     ///
     /// # Example
     ///
     /// ```
     /// use sortedlist_rs::SortedList;
     ///
-    /// let mut sorted_list: SortedList<i32> = SortedList::new();
-    /// assert_eq!(true, sorted_list.is_empty());
+    /// let mut sorted_list = SortedList::from([10, 20, 20, 30]);
     ///
-    /// sorted_list.insert(1);
-    /// assert_eq!(false, sorted_list.is_empty());
+    /// assert_eq!(true, sorted_list.discard(&20));
+    /// assert_eq!(false, sorted_list.discard(&25));
+    /// assert_eq!(3, sorted_list.len());
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn discard<Q>(&mut self, element: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.remove_value(element).is_some()
     }
 
-    /// Returns the last element of the SortedList, i.e. the largest element.
+    /// Removes every element equal to `element` from the SortedList.
+    /// Returns the number of removed elements.
     ///
     /// # Example
     ///
     /// ```
     /// use sortedlist_rs::SortedList;
     ///
-    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    /// let mut sorted_list = SortedList::from([10, 20, 20, 30]);
+    ///
+    /// assert_eq!(2, sorted_list.remove_all(&20));
+    /// assert_eq!(0, sorted_list.remove_all(&25));
+    /// assert_eq!(2, sorted_list.len());
+    /// ```
+    pub fn remove_all<Q>(&mut self, element: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.remove_range_by_value((Bound::Included(element), Bound::Included(element)))
+    }
+
+    /// Removes every element within the value range `range`. Returns the number of removed elements.
+    ///
+    /// Both ends are resolved by binary search, then the elements in between are removed
+    /// in O(k + number of inner lists), with a single rebalance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    ///
+    /// assert_eq!(3, sorted_list.remove_range_by_value(..30));
+    /// assert_eq!(vec![30, 99], sorted_list.to_vec());
+    /// ```
+    pub fn remove_range_by_value<Q, R>(&mut self, range: R) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+        R: RangeBounds<Q>,
+    {
+        self.drain_range_by_value(range).len()
+    }
+
+    /// Removes every element within the value range `range` and returns them as an iterator, in sorted order.
+    /// See [`SortedList::remove_range_by_value`] and [`SortedList::drain`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    /// let drained: Vec<i32> = sorted_list.drain_range_by_value(10..=20).collect();
+    ///
+    /// assert_eq!(vec![10, 20], drained);
+    /// assert_eq!(vec![2, 30, 99], sorted_list.to_vec());
+    /// ```
+    pub fn drain_range_by_value<Q, R>(&mut self, range: R) -> IntoIter<T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+        R: RangeBounds<Q>,
+    {
+        let removed = match self._range_positions(&range) {
+            Some((start, end)) => self._lists_remove_range(start, end),
+            None => vec![],
+        };
+        let len = removed.iter().map(Vec::len).sum();
+        IntoIter::_new(removed, len)
+    }
+
+    /// Removes the elements whose indices fall within `range` and returns them as an iterator, in sorted order.
+    /// Inner lists fully covered by `range` are moved out whole; only the two boundary lists are split.
+    ///
+    /// The elements are removed eagerly: dropping the iterator simply drops the remaining removed elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` goes out of bound, like `Vec::drain`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    /// let drained: Vec<i32> = sorted_list.drain(..2).collect();
+    ///
+    /// assert_eq!(vec![2, 10], drained);
+    /// assert_eq!(vec![20, 30, 99], sorted_list.to_vec());
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> IntoIter<T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range { start, end } = self._resolve_index_range(&range);
+        if start == end {
+            return IntoIter::_new(vec![], 0);
+        }
+
+        let removed =
+            self._lists_remove_range(self._position_of_rank(start), self._position_of_rank(end));
+        IntoIter::_new(removed, end - start)
+    }
+
+    /// Splits the SortedList into two at the given index.
+    /// Returns a new SortedList containing the elements from index `at` onwards, leaving `[0, at)` in `self`.
+    /// Whole inner lists are moved rather than copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    /// let other = sorted_list.split_off(2);
+    ///
+    /// assert_eq!(vec![2, 10], sorted_list.to_vec());
+    /// assert_eq!(vec![20, 30, 99], other.to_vec());
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        C: Clone,
+    {
+        if at > self._len {
+            panic!(
+                "SortedList: split index {} out of range for length {}.",
+                at, self._len
+            );
+        }
+        if self._len == 0 {
+            return Self::_with_comparator(self._cmp.clone());
+        }
+
+        self._lists_split_off(self._position_of_rank(at), at)
+    }
+
+    /// Splits the SortedList into two at the given value.
+    /// Returns a new SortedList containing every element not less than `element`, leaving the smaller ones in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    /// let other = sorted_list.split_off_value(&20);
+    ///
+    /// assert_eq!(vec![2, 10], sorted_list.to_vec());
+    /// assert_eq!(vec![20, 30, 99], other.to_vec());
+    /// ```
+    pub fn split_off_value<Q>(&mut self, element: &Q) -> Self
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q> + Clone,
+    {
+        if self._len == 0 {
+            return Self::_with_comparator(self._cmp.clone());
+        }
+
+        let (i, j) = self._bisect_left_position(element);
+        let len = self._lists_offset(i) + j;
+        self._lists_split_off((i, j), len)
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// If the two value ranges do not overlap, the inner lists of `other` are spliced onto `self` as they are.
+    /// Otherwise both SortedLists are merged linearly into freshly balanced lists.
+    /// Elements of `self` are kept ahead of equal elements of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99]);
+    /// let mut other = SortedList::from([20, 1, 50]);
+    /// sorted_list.append(&mut other);
+    ///
+    /// assert_eq!(vec![1, 2, 10, 20, 50, 99], sorted_list.to_vec());
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        if other.is_empty() {
+            return;
+        }
+
+        let mut other_lists = std::mem::take(&mut other._lists);
+        let other_len = other._len;
+        other.clear();
+
+        if self.is_empty() {
+            self._lists = other_lists;
+        } else if self._cmp.compare(self.last().unwrap(), &other_lists[0][0]) != Ordering::Greater {
+            self._lists.append(&mut other_lists);
+        } else if self._cmp.compare(
+            other_lists.last().unwrap().last().unwrap(),
+            self.first().unwrap(),
+        ) == Ordering::Less
+        {
+            // ties go to the merge below, which keeps the elements of self ahead of equal ones of other
+            other_lists.append(&mut self._lists);
+            self._lists = other_lists;
+        } else {
+            let load_factor = self._load_factor;
+            let mut lists: Vec<Vec<T>> = Vec::with_capacity(self._lists.len() + other_lists.len());
+            Self::_merge_sorted_with(
+                &self._cmp,
+                std::mem::take(&mut self._lists).into_iter().flatten(),
+                other_lists.into_iter().flatten(),
+                |element| match lists.last_mut() {
+                    Some(last) if last.len() < load_factor => last.push(element),
+                    _ => {
+                        let mut list = Vec::with_capacity(load_factor);
+                        list.push(element);
+                        lists.push(list);
+                    }
+                },
+            );
+            self._lists = lists;
+        }
+
+        self._len += other_len;
+        self._rebalance();
+    }
+
+    /// Merges two SortedLists into one. See [`SortedList::append`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let merged = SortedList::from([10, 2, 99]).merge(SortedList::from([20, 1, 50]));
+    ///
+    /// assert_eq!(vec![1, 2, 10, 20, 50, 99], merged.to_vec());
+    /// ```
+    pub fn merge(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+
+    /// Keeps the `n` smallest elements and drops the rest. Has no effect if `n >= len`.
+    /// Whole trailing inner lists are dropped at once, and the index tree is rebuilt only once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    /// sorted_list.truncate(2);
+    ///
+    /// assert_eq!(vec![2, 10], sorted_list.to_vec());
+    /// ```
+    pub fn truncate(&mut self, n: usize) {
+        if n >= self._len {
+            return;
+        }
+        if n == 0 {
+            self.clear();
+            return;
+        }
+
+        let (i, j) = self._locate_kth_element(n);
+        self._lists.truncate(i + 1);
+        self._lists[i].truncate(j);
+        self._len = n;
+        self._rebalance();
+    }
+
+    /// Keeps the `n` largest elements and drops the rest. Has no effect if `n >= len`.
+    /// Whole leading inner lists are dropped at once, and the index tree is rebuilt only once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    /// sorted_list.truncate_front(2);
+    ///
+    /// assert_eq!(vec![30, 99], sorted_list.to_vec());
+    /// ```
+    pub fn truncate_front(&mut self, n: usize) {
+        if n >= self._len {
+            return;
+        }
+        if n == 0 {
+            self.clear();
+            return;
+        }
+
+        let (i, j) = self._locate_kth_element(self._len - n);
+        self._lists.drain(..i);
+        self._lists[0].drain(..j);
+        self._len = n;
+        self._rebalance();
+    }
+
+    /// Removes consecutive duplicated elements, keeping one copy of every distinct element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 10, 2, 30]);
+    /// sorted_list.dedup();
+    ///
+    /// assert_eq!(vec![2, 10, 30], sorted_list.to_vec());
+    /// ```
+    pub fn dedup(&mut self) {
+        self._dedup_by(|cmp, a, b| cmp.compare(a, b) == Ordering::Equal);
+    }
+
+    /// Removes consecutive elements which resolve to the same key, keeping the first one of every run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([11, 2, 15, 3, 30]);
+    /// sorted_list.dedup_by_key(|x| x / 10);
+    ///
+    /// assert_eq!(vec![2, 11, 30], sorted_list.to_vec());
+    /// ```
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        self._dedup_by(|_, a, b| key(a) == key(b));
+    }
+
+    /// Returns an iterator yielding every distinct element together with its number of occurrences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 10, 2, 30, 10]);
+    /// let runs: Vec<(&i32, usize)> = sorted_list.runs().collect();
+    ///
+    /// assert_eq!(vec![(&2, 2), (&10, 3), (&30, 1)], runs);
+    /// ```
+    pub fn runs(&self) -> Runs<'_, T, C> {
+        Runs {
+            _iter: self.iter().peekable(),
+            _cmp: &self._cmp,
+        }
+    }
+
+    /// Retains only the elements for which `f` returns true, in a single pass.
+    /// The lists are rebalanced and the index tree is rebuilt only once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 33]);
+    /// sorted_list.retain(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(vec![2, 10, 20], sorted_list.to_vec());
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|element| f(element));
+    }
+
+    /// Retains only the elements for which `f` returns true, passing a mutable reference to each element.
+    ///
+    /// It is a logic error for `f` to modify an element in a way that changes its ordering
+    /// relative to the other elements, e.g. through fields which do not take part in `Ord`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([(1, 0), (2, 0), (3, 0)]);
+    /// sorted_list.retain_mut(|x| {
+    ///     x.1 += 1;
+    ///     x.0 != 2
+    /// });
+    ///
+    /// assert_eq!(vec![(1, 1), (3, 1)], sorted_list.to_vec());
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let len = self._len;
+        self._len = 0;
+        for list in self._lists.iter_mut() {
+            list.retain_mut(&mut f);
+            self._len += list.len();
+        }
+
+        if self._len != len {
+            self._rebalance();
+        }
+    }
+
+    /// Removes every element for which `pred` returns true and returns them in sorted order.
+    /// Unlike `Vec::extract_if`, the removal happens eagerly, with a single rebalance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 33]);
+    /// let odds = sorted_list.extract_if(|x| x % 2 == 1);
+    ///
+    /// assert_eq!(vec![33, 99], odds);
+    /// assert_eq!(vec![2, 10, 20], sorted_list.to_vec());
+    /// ```
+    pub fn extract_if<F>(&mut self, mut pred: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut removed = vec![];
+        for list in self._lists.iter_mut() {
+            let mut kept = Vec::with_capacity(list.len());
+            for element in list.drain(..) {
+                match pred(&element) {
+                    true => removed.push(element),
+                    false => kept.push(element),
+                }
+            }
+            *list = kept;
+        }
+
+        if !removed.is_empty() {
+            self._len -= removed.len();
+            self._rebalance();
+        }
+        removed
+    }
+
+    /// Binary searches the given element in the SortedList.
+    /// Returns Ok(i) for exact match, Err(i) otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    ///
+    /// let result = sorted_list.binary_search(&30);
+    /// assert_eq!(Ok(3), result);
+    ///
+    /// let result = sorted_list.binary_search(&90);
+    /// assert_eq!(Err(4), result);
+    /// ```
+    pub fn binary_search<Q>(&self, element: &Q) -> Result<usize, usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        if self._len == 0 {
+            return Err(0);
+        }
+
+        let i: usize = self._bisect_right_lists(element);
+        if i == 0 {
+            return self._lists[i].binary_search_by(|x| self._cmp.compare(x.borrow(), element));
+        }
+
+        match self._lists[i].binary_search_by(|x| self._cmp.compare(x.borrow(), element)) {
+            Ok(pos) => Ok(pos + self._index_tree_sum(0, i - 1, None, None, None)),
+            Err(pos) => Err(pos + self._index_tree_sum(0, i - 1, None, None, None)),
+        }
+    }
+
+    /// Binary searches the SortedList with a comparator function, like `slice::binary_search_by`.
+    /// `f` returns the ordering of an element relative to the target.
+    /// Returns Ok(i) for exact match, Err(i) otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    ///
+    /// assert_eq!(Ok(3), sorted_list.binary_search_by(|x| x.cmp(&30)));
+    /// assert_eq!(Err(4), sorted_list.binary_search_by(|x| x.cmp(&90)));
+    /// ```
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        if self._len == 0 {
+            return Err(0);
+        }
+
+        let i = self
+            ._lists
+            .partition_point(|list| f(list.last().unwrap()) == Ordering::Less);
+        if i == self._lists.len() {
+            return Err(self._len);
+        }
+
+        let offset = self._lists_offset(i);
+        match self._lists[i].binary_search_by(f) {
+            Ok(pos) => Ok(offset + pos),
+            Err(pos) => Err(offset + pos),
+        }
+    }
+
+    /// Binary searches the SortedList with a key extraction function, like `slice::binary_search_by_key`.
+    /// The SortedList must be ordered by the key as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([(1, "a"), (3, "b"), (5, "c")]);
+    ///
+    /// assert_eq!(Ok(1), sorted_list.binary_search_by_key(&3, |x| x.0));
+    /// assert_eq!(Err(2), sorted_list.binary_search_by_key(&4, |x| x.0));
+    /// ```
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|x| f(x).cmp(b))
+    }
+
+    /// Returns the index of the first element for which `pred` returns false, like `slice::partition_point`.
+    /// `pred` must hold for a prefix of the SortedList and fail for the rest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20, 30]);
+    ///
+    /// assert_eq!(3, sorted_list.partition_point(|x| *x < 25));
+    /// ```
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let i = self
+            ._lists
+            .partition_point(|list| list.last().is_some_and(&mut pred));
+        if i == self._lists.len() {
+            return self._len;
+        }
+
+        self._lists_offset(i) + self._lists[i].partition_point(pred)
+    }
+
+    /// Returns the index of the first element which is not less than `element`,
+    /// i.e. the leftmost position where `element` could be inserted while keeping the order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 20, 30]);
+    ///
+    /// assert_eq!(1, sorted_list.bisect_left(&20));
+    /// assert_eq!(0, sorted_list.bisect_left(&5));
+    /// assert_eq!(5, sorted_list.bisect_left(&99));
+    /// ```
+    pub fn bisect_left<Q>(&self, element: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        if self._len == 0 {
+            return 0;
+        }
+
+        let (i, j) = self._bisect_left_position(element);
+        self._lists_offset(i) + j
+    }
+
+    /// Returns the index of the first element which is greater than `element`,
+    /// i.e. the rightmost position where `element` could be inserted while keeping the order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 20, 30]);
+    ///
+    /// assert_eq!(4, sorted_list.bisect_right(&20));
+    /// assert_eq!(0, sorted_list.bisect_right(&5));
+    /// assert_eq!(5, sorted_list.bisect_right(&99));
+    /// ```
+    pub fn bisect_right<Q>(&self, element: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        if self._len == 0 {
+            return 0;
+        }
+
+        let (i, j) = self._bisect_right_position(element);
+        self._lists_offset(i) + j
+    }
+
+    /// Returns the range of indices holding elements equal to `element`.
+    /// The range is empty, starting at the insert position, if there is no such element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 20, 30]);
+    ///
+    /// assert_eq!(1..4, sorted_list.equal_range(&20));
+    /// assert_eq!(4..4, sorted_list.equal_range(&25));
+    /// ```
+    pub fn equal_range<Q>(&self, element: &Q) -> Range<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.bisect_left(element)..self.bisect_right(element)
+    }
+
+    /// Returns an iterator over the elements within the value range `range`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20, 30, 50]);
+    ///
+    /// let within: Vec<&i32> = sorted_list.range(10..50).collect();
+    /// assert_eq!(vec![&10, &20, &30], within);
+    ///
+    /// let within: Vec<&i32> = sorted_list.range(20..).rev().collect();
+    /// assert_eq!(vec![&99, &50, &30, &20], within);
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+        R: RangeBounds<Q>,
+    {
+        self._iter_positions(self._range_positions(&range))
+    }
+
+    /// Returns an iterator over the elements whose indices fall within `range`.
+    /// The first element is located once in O(log n), then the elements are streamed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` goes out of bound, like slice indexing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20, 30, 50]);
+    ///
+    /// let page: Vec<&i32> = sorted_list.iter_range(1..4).collect();
+    /// assert_eq!(vec![&10, &20, &30], page);
+    ///
+    /// let page: Vec<&i32> = sorted_list.iter_range(3..).rev().collect();
+    /// assert_eq!(vec![&99, &50, &30], page);
+    /// ```
+    pub fn iter_range<R>(&self, range: R) -> Iter<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range { start, end } = self._resolve_index_range(&range);
+        let positions = match start < end {
+            true => Some((self._position_of_rank(start), self._position_of_rank(end))),
+            false => None,
+        };
+        self._iter_positions(positions)
+    }
+
+    /// Returns an iterator over the elements with indices in \[start, stop).
+    /// Unlike [`SortedList::iter_range`], indices are clamped to the length of the SortedList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    ///
+    /// let page: Vec<&i32> = sorted_list.islice(2, 100).collect();
+    /// assert_eq!(vec![&20, &99], page);
+    ///
+    /// assert_eq!(None, sorted_list.islice(3, 1).next());
+    /// ```
+    pub fn islice(&self, start: usize, stop: usize) -> Iter<'_, T> {
+        let stop = stop.min(self._len);
+        let start = start.min(stop);
+        self.iter_range(start..stop)
+    }
+
+    /// Returns the last element which is not greater than `element`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 30]);
+    ///
+    /// assert_eq!(Some(&20), sorted_list.floor(&25));
+    /// assert_eq!(None, sorted_list.floor(&5));
+    /// ```
+    pub fn floor<Q>(&self, element: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self._floor_position(element).map(|(i, j)| self._at(i, j))
+    }
+
+    /// Returns the index of the last element which is not greater than `element`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 30]);
+    ///
+    /// assert_eq!(Some(2), sorted_list.floor_index(&20));
+    /// assert_eq!(None, sorted_list.floor_index(&5));
+    /// ```
+    pub fn floor_index<Q>(&self, element: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self._floor_position(element)
+            .map(|(i, j)| self._lists_offset(i) + j)
+    }
+
+    /// Returns the first element which is not less than `element`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 30]);
+    ///
+    /// assert_eq!(Some(&20), sorted_list.ceiling(&15));
+    /// assert_eq!(None, sorted_list.ceiling(&35));
+    /// ```
+    pub fn ceiling<Q>(&self, element: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self._ceiling_position(element).map(|(i, j)| self._at(i, j))
+    }
+
+    /// Returns the index of the first element which is not less than `element`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 30]);
+    ///
+    /// assert_eq!(Some(1), sorted_list.ceiling_index(&20));
+    /// assert_eq!(None, sorted_list.ceiling_index(&35));
+    /// ```
+    pub fn ceiling_index<Q>(&self, element: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self._ceiling_position(element)
+            .map(|(i, j)| self._lists_offset(i) + j)
+    }
+
+    /// Returns the last element which is strictly less than `element`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 30]);
+    ///
+    /// assert_eq!(Some(&10), sorted_list.lower(&20));
+    /// assert_eq!(None, sorted_list.lower(&10));
+    /// ```
+    pub fn lower<Q>(&self, element: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self._lower_position(element).map(|(i, j)| self._at(i, j))
+    }
+
+    /// Returns the index of the last element which is strictly less than `element`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 30]);
+    ///
+    /// assert_eq!(Some(2), sorted_list.lower_index(&30));
+    /// assert_eq!(None, sorted_list.lower_index(&10));
+    /// ```
+    pub fn lower_index<Q>(&self, element: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self._lower_position(element)
+            .map(|(i, j)| self._lists_offset(i) + j)
+    }
+
+    /// Returns the first element which is strictly greater than `element`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 30]);
+    ///
+    /// assert_eq!(Some(&30), sorted_list.higher(&20));
+    /// assert_eq!(None, sorted_list.higher(&30));
+    /// ```
+    pub fn higher<Q>(&self, element: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self._higher_position(element).map(|(i, j)| self._at(i, j))
+    }
+
+    /// Returns the index of the first element which is strictly greater than `element`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 30]);
+    ///
+    /// assert_eq!(Some(1), sorted_list.higher_index(&10));
+    /// assert_eq!(None, sorted_list.higher_index(&30));
+    /// ```
+    pub fn higher_index<Q>(&self, element: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self._higher_position(element)
+            .map(|(i, j)| self._lists_offset(i) + j)
+    }
+
+    /// Returns the number of elements equal to `element`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 20, 20, 20, 30]);
+    ///
+    /// assert_eq!(3, sorted_list.count(&20));
+    /// assert_eq!(0, sorted_list.count(&25));
+    /// ```
+    pub fn count<Q>(&self, element: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.bisect_right(element) - self.bisect_left(element)
+    }
+
+    /// Returns the number of elements within the value range `range`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20, 30, 50]);
+    ///
+    /// assert_eq!(3, sorted_list.count_range(10..50));
+    /// assert_eq!(3, sorted_list.count_range(..=20));
+    /// assert_eq!(0, sorted_list.count_range(60..90));
+    /// ```
+    pub fn count_range<Q, R>(&self, range: R) -> usize
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+        R: RangeBounds<Q>,
+    {
+        match self._range_positions(&range) {
+            Some(((i0, j0), (i1, j1))) => {
+                (self._lists_offset(i1) + j1) - (self._lists_offset(i0) + j0)
+            }
+            None => 0,
+        }
+    }
+
+    /// Returns whether the SortedList contains a specific element.
+    ///
+    /// Like the other lookup methods, it accepts any borrowed form of the element type,
+    /// e.g. a `&str` for a `SortedList<String>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    ///
+    /// assert_eq!(true, sorted_list.contains(&10));
+    /// assert_eq!(false, sorted_list.contains(&90));
+    ///
+    /// let names = SortedList::from([String::from("bob"), String::from("alice")]);
+    /// assert_eq!(true, names.contains("alice"));
+    /// ```
+    pub fn contains<Q>(&self, element: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.binary_search(element).is_ok()
+    }
+
+    /// Returns the number of elements stored in the SortedList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    ///
+    /// assert_eq!(4, sorted_list.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self._len
+    }
+
+    /// Returns whether the SortedList is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list: SortedList<i32> = SortedList::new();
+    /// assert_eq!(true, sorted_list.is_empty());
+    ///
+    /// sorted_list.insert(1);
+    /// assert_eq!(false, sorted_list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the last element of the SortedList, i.e. the largest element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
     ///
     /// assert_eq!(Some(&99), sorted_list.last());
     /// ```
@@ -482,7 +2035,7 @@ where
         if self.is_empty() {
             return None;
         }
-        return self._lists.last().unwrap().last();
+        self._lists.last().unwrap().last()
     }
 
     /// Returns the first element of the SortedList, i.e. the smallest element.
@@ -500,7 +2053,7 @@ where
         if self.is_empty() {
             return None;
         }
-        return self._lists.first().unwrap().first();
+        self._lists.first().unwrap().first()
     }
 
     /// Returns the element for the given index in the SortedList.
@@ -518,7 +2071,50 @@ where
         if self.is_empty() || self.len() <= index {
             return None;
         }
-        return Some(self.kth_smallest(index));
+        Some(self.kth_smallest(index))
+    }
+
+    /// Returns the k-th element (0-indexed) counting from the end of the SortedList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    ///
+    /// assert_eq!(Some(&99), sorted_list.get_from_end(0));
+    /// assert_eq!(Some(&20), sorted_list.get_from_end(1));
+    /// assert_eq!(None, sorted_list.get_from_end(4));
+    /// ```
+    pub fn get_from_end(&self, k: usize) -> Option<&T> {
+        if self.len() <= k {
+            return None;
+        }
+        let (i, j) = self._locate_kth_element(self._len - 1 - k);
+        Some(self._at(i, j))
+    }
+
+    /// Returns the element for the given Python-style signed index.
+    /// Negative indices count from the end, i.e. -1 refers to the last element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    ///
+    /// assert_eq!(Some(&2), sorted_list.get_signed(0));
+    /// assert_eq!(Some(&99), sorted_list.get_signed(-1));
+    /// assert_eq!(Some(&2), sorted_list.get_signed(-4));
+    /// assert_eq!(None, sorted_list.get_signed(-5));
+    /// ```
+    pub fn get_signed(&self, index: isize) -> Option<&T> {
+        match index < 0 {
+            true => self.get_from_end(index.unsigned_abs() - 1),
+            false => self.get(index as usize),
+        }
     }
 
     /// Returns a flattened view of the SortedList.
@@ -559,6 +2155,28 @@ where
         })
     }
 
+    /// Converts `self` into a `Vec` by moving its elements, without requiring `T: Clone`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    /// let v = sorted_list.into_vec();
+    ///
+    /// assert_eq!(vec![2, 10, 20, 99], v);
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        let mut lists = self._lists.into_iter();
+        let mut result = lists.next().unwrap_or_default();
+        result.reserve(self._len - result.len());
+        for mut list in lists {
+            result.append(&mut list);
+        }
+        result
+    }
+
     /// Returns an iterator over the elements of the SortedList.
     ///
     /// # Example
@@ -568,15 +2186,19 @@ where
     ///
     /// let sorted_list = SortedList::from([10, 2, 99, 20]);
     /// let mut iterator = sorted_list.iter();
-    /// 
+    ///
     /// assert_eq!(Some(&2), iterator.next());
     /// assert_eq!(Some(&10), iterator.next());
     /// assert_eq!(Some(&20), iterator.next());
     /// assert_eq!(Some(&99), iterator.next());
     /// assert_eq!(None, iterator.next());
-    /// 
-    pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self._lists.iter().flat_map(|list| list.iter())
+    ///
+    /// let reversed: Vec<&i32> = sorted_list.iter().rev().collect();
+    /// assert_eq!(vec![&99, &20, &10, &2], reversed);
+    /// assert_eq!(4, sorted_list.iter().len());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::_new(&self._lists, [].iter(), [].iter(), self._len)
     }
 }
 
@@ -586,13 +2208,13 @@ where
 {
     /// Creates an empty SortedList.
     fn default() -> Self {
-        Self::_default()
+        Self::new()
     }
 }
 
-impl<T> Index<usize> for SortedList<T>
+impl<T, C> Index<usize> for SortedList<T, C>
 where
-    T: Ord,
+    C: Comparator<T>,
 {
     type Output = T;
 
@@ -612,20 +2234,113 @@ where
     }
 }
 
-impl<T> From<IntoIter<T>> for SortedList<T>
+impl<T, C> IntoIterator for SortedList<T, C>
+where
+    C: Comparator<T>,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the SortedList into an iterator yielding its elements in sorted order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list = SortedList::from([10, 2, 99, 20]);
+    /// let v: Vec<i32> = sorted_list.into_iter().rev().collect();
+    ///
+    /// assert_eq!(vec![99, 20, 10, 2], v);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::_new(self._lists, self._len)
+    }
+}
+
+impl<'a, T, C> IntoIterator for &'a SortedList<T, C>
+where
+    C: Comparator<T>,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> FromIterator<T> for SortedList<T>
+where
+    T: Ord,
+{
+    /// Creates a SortedList from an iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let sorted_list: SortedList<i32> = [10, 2, 99, 20].into_iter().collect();
+    ///
+    /// assert_eq!(vec![2, 10, 20, 99], sorted_list.to_vec());
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T, C> Extend<T> for SortedList<T, C>
+where
+    C: Comparator<T>,
+{
+    /// Inserts a batch of elements. The batch is sorted and merged into the SortedList list by list,
+    /// which is faster than inserting the elements one by one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedList;
+    ///
+    /// let mut sorted_list = SortedList::from([10, 2, 99]);
+    /// sorted_list.extend(vec![20, 1, 50]);
+    ///
+    /// assert_eq!(vec![1, 2, 10, 20, 50, 99], sorted_list.to_vec());
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut batch: Vec<T> = iter.into_iter().collect();
+        batch.sort_by(|a, b| self._cmp.compare(a, b));
+        self._lists_insert_sorted(batch);
+    }
+}
+
+impl<'a, T, C> Extend<&'a T> for SortedList<T, C>
+where
+    T: Copy + 'a,
+    C: Comparator<T>,
+{
+    /// Inserts a batch of copied elements. See the `Extend<T>` implementation.
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> From<vec::IntoIter<T>> for SortedList<T>
 where
     T: Ord,
 {
     /// Creates a SortedList from an IntoIter
-    fn from(iter: IntoIter<T>) -> Self {
+    fn from(iter: vec::IntoIter<T>) -> Self {
         let mut array: Vec<T> = iter.collect();
         array.sort();
 
         // directly construct sorted_list's internals, i.e. _lists, _len
         // This method is way faster than inserting elements one by one
         let sorted_iter = array.into_iter();
-        let mut sorted_list = Self::default();
-        sorted_list._len = sorted_iter.len();
+        let mut sorted_list = Self {
+            _len: sorted_iter.len(),
+            ..Self::default()
+        };
 
         sorted_list._lists.push(vec![]);
         let mut last_list_size = 0;
@@ -639,6 +2354,11 @@ where
             }
         }
 
+        // avoid leaving an empty trailing list when the length is a multiple of the load factor
+        if sorted_list._lists.len() > 1 && sorted_list._lists.last().unwrap().is_empty() {
+            sorted_list._lists.pop();
+        }
+
         sorted_list._rebuild_index_tree();
         sorted_list
     }
@@ -684,9 +2404,281 @@ where
     }
 }
 
-impl<T> fmt::Debug for SortedList<T>
+/// An iterator over the elements of a SortedList, in sorted order.
+///
+/// Created by [`SortedList::iter`], [`SortedList::range`] and [`SortedList::iter_range`].
+pub struct Iter<'a, T> {
+    _lists: &'a [Vec<T>],
+    _front: slice::Iter<'a, T>,
+    _back: slice::Iter<'a, T>,
+    _len: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Creates an iterator yielding `front`, then every element of `lists`, then `back`.
+    fn _new(
+        lists: &'a [Vec<T>],
+        front: slice::Iter<'a, T>,
+        back: slice::Iter<'a, T>,
+        len: usize,
+    ) -> Self {
+        Self {
+            _lists: lists,
+            _front: front,
+            _back: back,
+            _len: len,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(element) = self._front.next() {
+                self._len -= 1;
+                return Some(element);
+            }
+
+            match self._lists.split_first() {
+                Some((first, rest)) => {
+                    self._front = first.iter();
+                    self._lists = rest;
+                }
+                None => {
+                    let element = self._back.next();
+                    if element.is_some() {
+                        self._len -= 1;
+                    }
+                    return element;
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self._len, Some(self._len))
+    }
+
+    fn count(self) -> usize {
+        self._len
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    /// Skips whole lists using their lengths instead of stepping through every element.
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self._len {
+            self._len = 0;
+            self._lists = &[];
+            self._front = [].iter();
+            self._back = [].iter();
+            return None;
+        }
+        self._len -= n + 1;
+
+        if n < self._front.len() {
+            return self._front.nth(n);
+        }
+        n -= self._front.len();
+        self._front = [].iter();
+
+        while let Some((first, rest)) = self._lists.split_first() {
+            self._lists = rest;
+            if n < first.len() {
+                self._front = first.iter();
+                return self._front.nth(n);
+            }
+            n -= first.len();
+        }
+
+        self._back.nth(n)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(element) = self._back.next_back() {
+                self._len -= 1;
+                return Some(element);
+            }
+
+            match self._lists.split_last() {
+                Some((last, rest)) => {
+                    self._back = last.iter();
+                    self._lists = rest;
+                }
+                None => {
+                    let element = self._front.next_back();
+                    if element.is_some() {
+                        self._len -= 1;
+                    }
+                    return element;
+                }
+            }
+        }
+    }
+
+    /// Skips whole lists using their lengths instead of stepping through every element.
+    fn nth_back(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self._len {
+            self._len = 0;
+            self._lists = &[];
+            self._front = [].iter();
+            self._back = [].iter();
+            return None;
+        }
+        self._len -= n + 1;
+
+        if n < self._back.len() {
+            return self._back.nth_back(n);
+        }
+        n -= self._back.len();
+        self._back = [].iter();
+
+        while let Some((last, rest)) = self._lists.split_last() {
+            self._lists = rest;
+            if n < last.len() {
+                self._back = last.iter();
+                return self._back.nth_back(n);
+            }
+            n -= last.len();
+        }
+
+        self._front.nth_back(n)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            _lists: self._lists,
+            _front: self._front.clone(),
+            _back: self._back.clone(),
+            _len: self._len,
+        }
+    }
+}
+
+/// An owning iterator over the elements of a SortedList, in sorted order.
+///
+/// Created by the `into_iter` method of [`SortedList`] and by [`SortedList::drain`].
+pub struct IntoIter<T> {
+    _lists: vec::IntoIter<Vec<T>>,
+    _front: vec::IntoIter<T>,
+    _back: vec::IntoIter<T>,
+    _len: usize,
+}
+
+impl<T> IntoIter<T> {
+    /// Creates an iterator yielding every element of `lists`, which holds `len` elements in total.
+    fn _new(lists: Vec<Vec<T>>, len: usize) -> Self {
+        Self {
+            _lists: lists.into_iter(),
+            _front: Vec::new().into_iter(),
+            _back: Vec::new().into_iter(),
+            _len: len,
+        }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(element) = self._front.next() {
+                self._len -= 1;
+                return Some(element);
+            }
+
+            match self._lists.next() {
+                Some(list) => self._front = list.into_iter(),
+                None => {
+                    let element = self._back.next();
+                    if element.is_some() {
+                        self._len -= 1;
+                    }
+                    return element;
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self._len, Some(self._len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(element) = self._back.next_back() {
+                self._len -= 1;
+                return Some(element);
+            }
+
+            match self._lists.next_back() {
+                Some(list) => self._back = list.into_iter(),
+                None => {
+                    let element = self._front.next_back();
+                    if element.is_some() {
+                        self._len -= 1;
+                    }
+                    return element;
+                }
+            }
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// An iterator over the runs of equal elements of a SortedList, yielding `(element, count)`.
+///
+/// Created by [`SortedList::runs`].
+pub struct Runs<'a, T, C = Natural> {
+    _iter: Peekable<Iter<'a, T>>,
+    _cmp: &'a C,
+}
+
+impl<'a, T, C> Iterator for Runs<'a, T, C>
+where
+    C: Comparator<T>,
+{
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self._iter.next()?;
+        let mut count = 1;
+        let cmp = self._cmp;
+        while self
+            ._iter
+            .next_if(|element| cmp.compare(element, first) == Ordering::Equal)
+            .is_some()
+        {
+            count += 1;
+        }
+        Some((first, count))
+    }
+}
+
+impl<'a, T, C> FusedIterator for Runs<'a, T, C> where C: Comparator<T> {}
+
+impl<T, C> fmt::Debug for SortedList<T, C>
 where
-    T: Ord + Debug,
+    T: Debug,
+    C: Comparator<T>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self._flat(), f)
@@ -695,9 +2687,47 @@ where
 
 #[cfg(test)]
 mod tests {
-    use rand::{seq::SliceRandom, thread_rng, Rng};
+    use std::{
+        cmp::{Ordering, Reverse},
+        ops::Bound,
+    };
+
+    use rand::{rngs::StdRng, seq::SliceRandom, thread_rng, Rng, SeedableRng};
+
+    use crate::{Comparator, Natural, SortedList};
+
+    /// Asserts that the internals of `sorted_list` are consistent with each other.
+    fn assert_internals<T, C: Comparator<T>>(sorted_list: &SortedList<T, C>) {
+        let sizes: Vec<usize> = sorted_list._lists.iter().map(|list| list.len()).collect();
+        assert_eq!(sorted_list._len, sizes.iter().sum::<usize>());
+        assert_eq!(sorted_list._len, sorted_list._index_tree[1]);
+        for (i, size) in sizes.iter().enumerate() {
+            assert_eq!(
+                *size,
+                sorted_list._index_tree[sorted_list._index_tree_offset + i]
+            );
+            assert!(*size <= sorted_list._upper_load_factor);
+            assert!(sorted_list._len == 0 || *size > 0);
+        }
+        assert!(sorted_list
+            ._lists
+            .iter()
+            .flatten()
+            .zip(sorted_list._lists.iter().flatten().skip(1))
+            .all(|(a, b)| sorted_list._cmp.compare(a, b) != Ordering::Greater));
+    }
 
-    use crate::SortedList;
+    /// Returns a seeded rng, a SortedList of `size` random elements from `range`, and the same elements as a sorted Vec.
+    fn random_list(
+        size: usize,
+        range: std::ops::Range<i32>,
+    ) -> (StdRng, SortedList<i32>, Vec<i32>) {
+        let mut rng = StdRng::seed_from_u64(size as u64);
+        let mut array: Vec<i32> = (0..size).map(|_| rng.gen_range(range.clone())).collect();
+        let sorted_list = SortedList::from(array.clone());
+        array.sort();
+        (rng, sorted_list, array)
+    }
 
     #[test]
     fn it_works() {
@@ -815,6 +2845,455 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn bisect_test() {
+        // arrange
+        let (_, sorted_list, array) = random_list(20_000, 0..50);
+
+        // assert
+        for x in -1..51 {
+            let left = array.partition_point(|e| e < &x);
+            let right = array.partition_point(|e| e <= &x);
+            assert_eq!(left, sorted_list.bisect_left(&x));
+            assert_eq!(right, sorted_list.bisect_right(&x));
+            assert_eq!(left..right, sorted_list.equal_range(&x));
+        }
+    }
+
+    #[test]
+    fn bisect_duplicates_test() {
+        let sorted_list = SortedList::from([10; 10_000]);
+
+        assert_eq!(0, sorted_list.bisect_left(&10));
+        assert_eq!(10_000, sorted_list.bisect_right(&10));
+        assert_eq!(0..0, sorted_list.equal_range(&9));
+        assert_eq!(10_000..10_000, sorted_list.equal_range(&11));
+
+        let empty: SortedList<i32> = SortedList::new();
+        assert_eq!(0..0, empty.equal_range(&1));
+    }
+
+    #[test]
+    fn insert_after_from_full_lists() {
+        let mut sorted_list = SortedList::from(vec![0; 1_024]);
+        sorted_list.insert(5);
+
+        assert_eq!(1_025, sorted_list.len());
+        assert_eq!(Some(&5), sorted_list.last());
+    }
+
+    #[test]
+    fn range_test() {
+        // arrange
+        let (mut rng, sorted_list, array) = random_list(20_000, 0..1_000);
+
+        for _ in 0..200 {
+            let a = rng.gen_range(-10..1_010);
+            let b = rng.gen_range(-10..1_010);
+
+            // act
+            let actual: Vec<&i32> = sorted_list.range(a..b).collect();
+            let actual_inclusive: Vec<&i32> = sorted_list.range(a..=b).rev().collect();
+            let actual_excluded: Vec<&i32> = sorted_list
+                .range((Bound::Excluded(a), Bound::Unbounded))
+                .collect();
+
+            // assert
+            let expected: Vec<&i32> = array.iter().filter(|x| (a..b).contains(x)).collect();
+            let expected_inclusive: Vec<&i32> =
+                array.iter().rev().filter(|x| (a..=b).contains(x)).collect();
+            let expected_excluded: Vec<&i32> = array.iter().filter(|x| **x > a).collect();
+            assert_eq!(expected, actual);
+            assert_eq!(expected_inclusive, actual_inclusive);
+            assert_eq!(expected_excluded, actual_excluded);
+        }
+
+        let empty: SortedList<i32> = SortedList::new();
+        assert_eq!(None, empty.range(..).next());
+    }
+
+    #[test]
+    fn count_test() {
+        // arrange
+        let (mut rng, sorted_list, array) = random_list(20_000, 0..100);
+
+        // assert
+        for x in -1..101 {
+            let expected = array.iter().filter(|e| **e == x).count();
+            assert_eq!(expected, sorted_list.count(&x));
+        }
+
+        for _ in 0..200 {
+            let a = rng.gen_range(-10..110);
+            let b = rng.gen_range(-10..110);
+            let expected = array.iter().filter(|e| (a..b).contains(*e)).count();
+            assert_eq!(expected, sorted_list.count_range(a..b));
+
+            let expected = array.iter().filter(|e| (a..=b).contains(*e)).count();
+            assert_eq!(expected, sorted_list.count_range(a..=b));
+        }
+
+        assert_eq!(20_000, sorted_list.count_range(..));
+    }
+
+    #[test]
+    fn floor_ceiling_lower_higher_test() {
+        // arrange
+        let (_, sorted_list, array) = random_list(20_000, 0..100);
+
+        // assert
+        for x in -1..101 {
+            let left = array.partition_point(|e| e < &x);
+            let right = array.partition_point(|e| e <= &x);
+
+            let floor = right.checked_sub(1);
+            let ceiling = Some(left).filter(|i| *i < array.len());
+            let lower = left.checked_sub(1);
+            let higher = Some(right).filter(|i| *i < array.len());
+
+            assert_eq!(floor, sorted_list.floor_index(&x));
+            assert_eq!(ceiling, sorted_list.ceiling_index(&x));
+            assert_eq!(lower, sorted_list.lower_index(&x));
+            assert_eq!(higher, sorted_list.higher_index(&x));
+
+            assert_eq!(floor.map(|i| &array[i]), sorted_list.floor(&x));
+            assert_eq!(ceiling.map(|i| &array[i]), sorted_list.ceiling(&x));
+            assert_eq!(lower.map(|i| &array[i]), sorted_list.lower(&x));
+            assert_eq!(higher.map(|i| &array[i]), sorted_list.higher(&x));
+        }
+
+        let empty: SortedList<i32> = SortedList::new();
+        assert_eq!(None, empty.floor(&1));
+        assert_eq!(None, empty.higher_index(&1));
+    }
+
+    #[test]
+    fn pop_first_last_test() {
+        // arrange
+        let (mut rng, mut sorted_list, array) = random_list(20_000, i32::MIN..i32::MAX);
+        let mut copy = std::collections::VecDeque::from(array);
+
+        // act & assert
+        while !copy.is_empty() {
+            match rng.gen::<bool>() {
+                true => assert_eq!(copy.pop_front(), sorted_list.pop_first()),
+                false => assert_eq!(copy.pop_back(), sorted_list.pop_last()),
+            }
+            assert_eq!(copy.len(), sorted_list.len());
+        }
+
+        assert_eq!(None, sorted_list.pop_first());
+        assert_eq!(None, sorted_list.pop_last());
+        assert_internals(&sorted_list);
+
+        sorted_list.insert(1);
+        assert_eq!(Some(1), sorted_list.pop_last());
+    }
+
+    #[test]
+    fn remove_value_test() {
+        // arrange
+        let (mut rng, mut sorted_list, mut array) = random_list(20_000, 0..1_000);
+
+        for _ in 0..5_000 {
+            let x = rng.gen_range(0..1_000);
+
+            // act
+            let actual = sorted_list.remove_value(&x);
+
+            // assert
+            let expected = array.binary_search(&x).ok().map(|i| array.remove(i));
+            assert_eq!(expected, actual);
+            assert_eq!(array.len(), sorted_list.len());
+        }
+        assert_eq!(array, sorted_list.to_vec());
+        assert_internals(&sorted_list);
+    }
+
+    #[test]
+    fn remove_all_test() {
+        // arrange
+        let (_, mut sorted_list, mut array) = random_list(20_000, 0..20);
+
+        for x in 0..20 {
+            // act
+            let actual = sorted_list.remove_all(&x);
+
+            // assert
+            let expected = array.iter().filter(|e| **e == x).count();
+            array.retain(|e| *e != x);
+            assert_eq!(expected, actual);
+            assert_eq!(array.len(), sorted_list.len());
+            assert_eq!(array, sorted_list.to_vec());
+            assert!(!sorted_list.discard(&x));
+            assert_internals(&sorted_list);
+        }
+
+        assert!(sorted_list.is_empty());
+        sorted_list.insert(3);
+        assert_eq!(Some(&3), sorted_list.first());
+    }
+
+    #[test]
+    fn iter_range_test() {
+        // arrange
+        let (mut rng, sorted_list, array) = random_list(20_000, i32::MIN..i32::MAX);
+
+        for _ in 0..200 {
+            let a = rng.gen_range(0..=array.len());
+            let b = rng.gen_range(a..=array.len());
+
+            // act
+            let actual: Vec<&i32> = sorted_list.iter_range(a..b).collect();
+            let actual_rev: Vec<&i32> = sorted_list.iter_range(a..).rev().collect();
+            let actual_islice: Vec<&i32> = sorted_list.islice(a, b + 100).collect();
+
+            // assert
+            assert_eq!(array[a..b].iter().collect::<Vec<&i32>>(), actual);
+            assert_eq!(array[a..].iter().rev().collect::<Vec<&i32>>(), actual_rev);
+            let stop = (b + 100).min(array.len());
+            assert_eq!(array[a..stop].iter().collect::<Vec<&i32>>(), actual_islice);
+        }
+
+        let empty: SortedList<i32> = SortedList::new();
+        assert_eq!(None, empty.iter_range(..).next());
+        assert_eq!(None, empty.islice(0, 10).next());
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_range_out_of_bound() {
+        let sorted_list = SortedList::from([1, 2, 3]);
+        sorted_list.iter_range(1..4).next();
+    }
+
+    #[test]
+    #[should_panic(expected = "SortedList: Index out of range.")]
+    fn iter_range_inclusive_usize_max() {
+        let sorted_list = SortedList::from([1, 2, 3]);
+        sorted_list.iter_range(0..=usize::MAX).next();
+    }
+
+    #[test]
+    #[should_panic(expected = "SortedList: Index out of range.")]
+    fn drain_excluded_usize_max() {
+        let mut sorted_list = SortedList::from([1, 2, 3]);
+        sorted_list.drain((Bound::Excluded(usize::MAX), Bound::Unbounded));
+    }
+
+    #[test]
+    fn iter_test() {
+        // arrange
+        let (mut rng, sorted_list, array) = random_list(20_000, i32::MIN..i32::MAX);
+
+        // assert
+        assert_eq!(array.len(), sorted_list.iter().len());
+        assert!(array.iter().eq(sorted_list.iter()));
+        assert!(array.iter().rev().eq(sorted_list.iter().rev()));
+
+        for _ in 0..200 {
+            let a = rng.gen_range(0..=array.len());
+            let b = rng.gen_range(a..=array.len());
+            let n = rng.gen_range(0..3_000);
+
+            let mut expected = array[a..b].iter();
+            let mut actual = sorted_list.iter_range(a..b);
+            assert_eq!(expected.nth(n), actual.nth(n));
+            assert_eq!(expected.nth_back(n), actual.nth_back(n));
+            assert_eq!(expected.next_back(), actual.next_back());
+            assert_eq!(expected.len(), actual.len());
+            assert!(expected.eq(actual));
+        }
+
+        // interleave both ends
+        let mut expected = array.iter();
+        let mut actual = sorted_list.iter();
+        while expected.len() > 0 {
+            match rng.gen::<bool>() {
+                true => assert_eq!(expected.next(), actual.next()),
+                false => assert_eq!(expected.next_back(), actual.next_back()),
+            }
+            assert_eq!(expected.len(), actual.len());
+        }
+        assert_eq!(None, actual.next());
+        assert_eq!(None, actual.next_back());
+    }
+
+    #[test]
+    fn borrowed_lookup_test() {
+        // arrange
+        let words = ["pear", "apple", "fig", "kiwi", "apple", "banana"];
+        let mut sorted_list: SortedList<String> = words.iter().map(|w| w.to_string()).collect();
+
+        // assert
+        assert!(sorted_list.contains("fig"));
+        assert!(!sorted_list.contains("grape"));
+        assert_eq!(Ok(3), sorted_list.binary_search("fig"));
+        assert_eq!(0..2, sorted_list.equal_range("apple"));
+        assert_eq!(2, sorted_list.count("apple"));
+        assert_eq!(Some(&String::from("kiwi")), sorted_list.ceiling("grape"));
+        assert_eq!(Some(&String::from("fig")), sorted_list.floor("grape"));
+        assert_eq!(
+            vec!["banana", "fig"],
+            sorted_list
+                .range::<str, _>((Bound::Excluded("apple"), Bound::Excluded("kiwi")))
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(Some(String::from("pear")), sorted_list.remove_value("pear"));
+        assert_eq!(2, sorted_list.remove_all("apple"));
+        assert_eq!(vec!["banana", "fig", "kiwi"], sorted_list.to_vec());
+    }
+
+    #[test]
+    fn search_by_test() {
+        // arrange
+        let mut rng = thread_rng();
+        let mut array: Vec<(i32, i32)> = (0..20_000)
+            .map(|_| (rng.gen_range(0..5_000), rng.gen()))
+            .collect();
+        let sorted_list = SortedList::from(array.clone());
+        array.sort();
+
+        // assert
+        for k in -1..5_001 {
+            let expected = array.partition_point(|x| x.0 < k);
+            assert_eq!(expected, sorted_list.partition_point(|x| x.0 < k));
+
+            let actual = sorted_list.binary_search_by_key(&k, |x| x.0);
+            match array.binary_search_by_key(&k, |x| x.0) {
+                Ok(_) => assert_eq!(k, array[actual.unwrap()].0),
+                Err(i) => assert_eq!(Err(i), actual),
+            }
+        }
+
+        for x in array.iter().step_by(97) {
+            let i = sorted_list.binary_search_by(|e| e.cmp(x)).unwrap();
+            assert_eq!(x, &sorted_list[i]);
+        }
+
+        let empty: SortedList<i32> = SortedList::new();
+        assert_eq!(0, empty.partition_point(|x| *x < 1));
+        assert_eq!(Err(0), empty.binary_search_by(|x| x.cmp(&1)));
+
+        let mut emptied = SortedList::from([1]);
+        emptied.remove(0);
+        assert_eq!(0, emptied.partition_point(|x| *x < 1));
+        assert_eq!(Err(0), emptied.binary_search_by(|x| x.cmp(&1)));
+    }
+
+    #[test]
+    fn reverse_indexing_test() {
+        // arrange
+        let (mut rng, sorted_list, array) = random_list(20_000, i32::MIN..i32::MAX);
+        let n = array.len();
+
+        // assert
+        for _ in 0..5_000 {
+            let k = rng.gen_range(0..n);
+            assert_eq!(&array[n - 1 - k], sorted_list.kth_largest(k));
+            assert_eq!(Some(&array[n - 1 - k]), sorted_list.get_from_end(k));
+            assert_eq!(
+                Some(&array[n - 1 - k]),
+                sorted_list.get_signed(-(k as isize) - 1)
+            );
+            assert_eq!(Some(&array[k]), sorted_list.get_signed(k as isize));
+        }
+
+        assert_eq!(None, sorted_list.get_from_end(n));
+        assert_eq!(None, sorted_list.get_signed(-(n as isize) - 1));
+        assert_eq!(None, sorted_list.get_signed(n as isize));
+        assert_eq!(None, sorted_list.get_signed(isize::MIN));
+        assert_eq!(None, SortedList::<i32>::new().get_signed(-1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn kth_largest_out_of_bound() {
+        let sorted_list = SortedList::from([1, 2, 3]);
+        sorted_list.kth_largest(3);
+    }
+
+    #[test]
+    fn comparator_test() {
+        // arrange
+        let mut rng = thread_rng();
+        let mut copy: Vec<i32> = (0..20_000).map(|_| rng.gen_range(0..5_000)).collect();
+        let mut sorted_list = SortedList::with_comparator(Reverse(Natural));
+        sorted_list.extend(copy.iter().take(10_000));
+        for x in copy.iter().skip(10_000) {
+            sorted_list.insert(*x);
+        }
+        copy.sort_by(|a, b| b.cmp(a));
+
+        // assert
+        assert_eq!(copy, sorted_list.to_vec());
+        assert_internals(&sorted_list);
+        for x in -1..5_001 {
+            let left = copy.partition_point(|e| *e > x);
+            let right = copy.partition_point(|e| *e >= x);
+            assert_eq!(left..right, sorted_list.equal_range(&x));
+            assert_eq!(
+                copy.binary_search_by(|e| x.cmp(e)).is_ok(),
+                sorted_list.contains(&x)
+            );
+            assert_eq!(right.checked_sub(1), sorted_list.floor_index(&x));
+        }
+        assert!(copy
+            .iter()
+            .filter(|x| (100..=200).contains(*x))
+            .eq(sorted_list.range((Bound::Included(200), Bound::Included(100)))));
+
+        // act
+        for _ in 0..2_000 {
+            let x = rng.gen_range(0..5_000);
+            let expected = copy.iter().position(|e| *e == x).map(|i| copy.remove(i));
+            assert_eq!(expected, sorted_list.remove_value(&x));
+        }
+        let other = sorted_list.split_off_value(&2_500);
+        sorted_list.dedup();
+
+        // assert
+        let k = copy.partition_point(|e| *e > 2_500);
+        let mut expected = copy[..k].to_vec();
+        expected.dedup();
+        assert_eq!(expected, sorted_list.to_vec());
+        assert_eq!(copy[k..], other.to_vec());
+        assert_internals(&sorted_list);
+        assert_internals(&other);
+    }
+
+    #[test]
+    fn closure_comparator_test() {
+        // arrange
+        let by_len = |a: &String, b: &String| a.len().cmp(&b.len());
+        let mut sorted_list = SortedList::with_comparator(by_len);
+        let mut other = SortedList::with_comparator(by_len);
+
+        // act
+        sorted_list.extend(["ccc", "a", "bb"].map(String::from));
+        other.extend(["dd", "eeee"].map(String::from));
+        sorted_list.append(&mut other);
+
+        // assert
+        let lengths: Vec<usize> = sorted_list.iter().map(|s| s.len()).collect();
+        assert_eq!(vec![1, 2, 2, 3, 4], lengths);
+        assert_eq!(2, sorted_list.count(&String::from("xx")));
+        assert_eq!(
+            vec![(1, 1), (2, 2), (3, 1), (4, 1)],
+            sorted_list
+                .runs()
+                .map(|(s, n)| (s.len(), n))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Ordering::Less,
+            sorted_list
+                .comparator()
+                .compare(&sorted_list[0], &sorted_list[1])
+        );
+    }
+
     #[test]
     fn contains_test() {
         let mut sorted_list = SortedList::from([10; 10_000]);
@@ -865,6 +3344,356 @@ mod tests {
     }
 
     #[test]
+    fn into_iter_test() {
+        // arrange
+        let (mut rng, sorted_list, array) = random_list(20_000, i32::MIN..i32::MAX);
+
+        // act
+        let mut actual = sorted_list.into_iter();
+        let mut expected = array.into_iter();
+
+        // assert
+        while expected.len() > 0 {
+            match rng.gen::<bool>() {
+                true => assert_eq!(expected.next(), actual.next()),
+                false => assert_eq!(expected.next_back(), actual.next_back()),
+            }
+            assert_eq!(expected.len(), actual.len());
+        }
+        assert_eq!(None, actual.next());
+        assert_eq!(None, actual.next_back());
+    }
+
+    #[test]
+    fn into_vec_test() {
+        // arrange
+        struct Payload(usize);
+
+        impl PartialEq for Payload {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Payload {}
+        impl PartialOrd for Payload {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Payload {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut rng = thread_rng();
+        let mut array: Vec<usize> = (0..5_000).collect();
+        array.shuffle(&mut rng);
+        let sorted_list = SortedList::from(array.into_iter().map(Payload).collect::<Vec<_>>());
+
+        // act
+        let v = sorted_list.into_vec();
+
+        // assert
+        assert!(v.iter().map(|p| p.0).eq(0..5_000));
+        assert!(SortedList::<Payload>::new().into_vec().is_empty());
+
+        let mut total = 0;
+        for x in &SortedList::from([3, 1, 2]) {
+            total += x;
+        }
+        assert_eq!(6, total);
+    }
+
+    #[test]
+    fn extend_test() {
+        // arrange
+        let mut rng = thread_rng();
+        let mut copy: Vec<i32> = vec![];
+        let mut sorted_list: SortedList<i32> = (0..0).collect();
+
+        for batch_size in [0, 1, 10, 5_000, 100, 20_000, 3] {
+            let batch: Vec<i32> = (0..batch_size).map(|_| rng.gen_range(0..10_000)).collect();
+
+            // act
+            match rng.gen::<bool>() {
+                true => sorted_list.extend(batch.clone()),
+                false => sorted_list.extend(&batch),
+            }
+
+            // assert
+            copy.extend(batch);
+            copy.sort();
+            assert_eq!(copy, sorted_list.to_vec());
+            assert_internals(&sorted_list);
+        }
+
+        let collected: SortedList<i32> = copy.iter().rev().copied().collect();
+        assert_eq!(copy, collected.into_vec());
+    }
+
+    #[test]
+    fn retain_test() {
+        // arrange
+        let mut rng = thread_rng();
+        let mut copy: Vec<i32> = (0..50_000).map(|_| rng.gen_range(0..1_000)).collect();
+        let mut sorted_list = SortedList::from(copy.clone());
+        copy.sort();
+
+        for m in [7, 3, 1_000] {
+            // act
+            sorted_list.retain(|x| x % m != 0);
+            let extracted = sorted_list.extract_if(|x| x % (m + 1) == 0);
+
+            // assert
+            let expected_extracted: Vec<i32> = copy
+                .iter()
+                .copied()
+                .filter(|x| x % m != 0 && x % (m + 1) == 0)
+                .collect();
+            copy.retain(|x| x % m != 0 && x % (m + 1) != 0);
+            assert_eq!(expected_extracted, extracted);
+            assert_eq!(copy, sorted_list.to_vec());
+            assert_internals(&sorted_list);
+        }
+
+        sorted_list.retain_mut(|_| false);
+        assert!(sorted_list.is_empty());
+        assert_internals(&sorted_list);
+        sorted_list.insert(1);
+        assert_eq!(Some(&1), sorted_list.first());
+    }
+
+    #[test]
+    fn drain_test() {
+        // arrange
+        let mut rng = thread_rng();
+        let mut copy: Vec<i32> = (0..50_000).map(|_| rng.gen::<i32>()).collect();
+        let mut sorted_list = SortedList::from(copy.clone());
+        copy.sort();
+
+        while !copy.is_empty() {
+            let a = rng.gen_range(0..=copy.len());
+            let b = rng.gen_range(a..=copy.len().min(a + 8_000));
+
+            // act
+            let actual: Vec<i32> = sorted_list.drain(a..b).collect();
+
+            // assert
+            let expected: Vec<i32> = copy.drain(a..b).collect();
+            assert_eq!(expected, actual);
+            assert_eq!(copy, sorted_list.to_vec());
+            assert_internals(&sorted_list);
+        }
+
+        let mut sorted_list = SortedList::from([3, 1, 2]);
+        assert_eq!(
+            vec![3, 2, 1],
+            sorted_list.drain(..).rev().collect::<Vec<i32>>()
+        );
+        assert!(sorted_list.is_empty());
+    }
+
+    #[test]
+    fn remove_range_by_value_test() {
+        // arrange
+        let mut rng = thread_rng();
+        let mut copy: Vec<i32> = (0..50_000).map(|_| rng.gen_range(0..100_000)).collect();
+        let mut sorted_list = SortedList::from(copy.clone());
+        copy.sort();
+
+        while !copy.is_empty() {
+            let a = rng.gen_range(-10..100_010);
+            let b = rng.gen_range(a..a + 20_000);
+
+            // act & assert
+            match rng.gen::<bool>() {
+                true => {
+                    let expected = copy.iter().filter(|x| (a..b).contains(*x)).count();
+                    assert_eq!(expected, sorted_list.remove_range_by_value(a..b));
+                    copy.retain(|x| !(a..b).contains(x));
+                }
+                false => {
+                    let expected: Vec<i32> = copy
+                        .iter()
+                        .copied()
+                        .filter(|x| (a..=b).contains(x))
+                        .collect();
+                    let drained: Vec<i32> = sorted_list.drain_range_by_value(a..=b).collect();
+                    assert_eq!(expected, drained);
+                    copy.retain(|x| !(a..=b).contains(x));
+                }
+            }
+            assert_eq!(copy, sorted_list.to_vec());
+            assert_internals(&sorted_list);
+        }
+    }
+
+    #[test]
+    fn split_off_test() {
+        // arrange
+        let (mut rng, _, array) = random_list(20_000, 0..10_000);
+
+        for _ in 0..20 {
+            let at = rng.gen_range(0..=array.len());
+            let x = rng.gen_range(-10..10_010);
+            let mut sorted_list = SortedList::from(array.clone());
+            let mut by_value = SortedList::from(array.clone());
+
+            // act
+            let other = sorted_list.split_off(at);
+            let other_by_value = by_value.split_off_value(&x);
+
+            // assert
+            assert_eq!(array[..at], sorted_list.to_vec());
+            assert_eq!(array[at..], other.to_vec());
+            let k = array.partition_point(|e| e < &x);
+            assert_eq!(array[..k], by_value.to_vec());
+            assert_eq!(array[k..], other_by_value.to_vec());
+            for list in [&sorted_list, &other, &by_value, &other_by_value] {
+                assert_internals(list);
+            }
+        }
+
+        let mut empty: SortedList<i32> = SortedList::new();
+        assert!(empty.split_off(0).is_empty());
+        assert!(empty.split_off_value(&1).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bound() {
+        let mut sorted_list = SortedList::from([1, 2, 3]);
+        sorted_list.split_off(4);
+    }
+
+    #[test]
+    fn append_test() {
+        // arrange
+        let mut rng = thread_rng();
+        let random_array = |rng: &mut rand::rngs::ThreadRng, lo: i32, hi: i32| -> Vec<i32> {
+            let size = rng.gen_range(0..10_000);
+            (0..size).map(|_| rng.gen_range(lo..hi)).collect()
+        };
+
+        for _ in 0..20 {
+            // overlapping, disjoint ascending and disjoint descending value ranges
+            let (a, b) = match rng.gen_range(0..3) {
+                0 => (
+                    random_array(&mut rng, 0, 1_000),
+                    random_array(&mut rng, 0, 1_000),
+                ),
+                1 => (
+                    random_array(&mut rng, 0, 500),
+                    random_array(&mut rng, 500, 1_000),
+                ),
+                _ => (
+                    random_array(&mut rng, 500, 1_000),
+                    random_array(&mut rng, 0, 500),
+                ),
+            };
+            let mut expected = [a.clone(), b.clone()].concat();
+            expected.sort();
+
+            // act
+            let mut sorted_list = SortedList::from(a.clone());
+            let mut other = SortedList::from(b.clone());
+            sorted_list.append(&mut other);
+            let merged = SortedList::from(a).merge(SortedList::from(b));
+
+            // assert
+            assert_eq!(expected, sorted_list.to_vec());
+            assert_eq!(expected, merged.to_vec());
+            assert!(other.is_empty());
+            assert_internals(&sorted_list);
+            assert_internals(&merged);
+            assert_internals(&other);
+        }
+
+        // equal elements of self stay ahead of the ones of other, whichever path runs
+        let by_key = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+        for (a, b) in [
+            ([(1, 'a'), (2, 'a')], [(0, 'b'), (1, 'b')]),
+            ([(0, 'a'), (1, 'a')], [(1, 'b'), (2, 'b')]),
+        ] {
+            let mut sorted_list = SortedList::with_comparator(by_key);
+            sorted_list.extend(a);
+            let mut other = SortedList::with_comparator(by_key);
+            other.extend(b);
+            sorted_list.append(&mut other);
+
+            let mut expected = [a, b].concat();
+            expected.sort_by(by_key);
+            assert_eq!(expected, sorted_list.to_vec());
+        }
+    }
+
+    #[test]
+    fn truncate_test() {
+        // arrange
+        let (mut rng, _, array) = random_list(20_000, i32::MIN..i32::MAX);
+
+        for _ in 0..20 {
+            let n = rng.gen_range(0..array.len() + 10);
+            let mut sorted_list = SortedList::from(array.clone());
+            let mut sorted_list_front = SortedList::from(array.clone());
+
+            // act
+            sorted_list.truncate(n);
+            sorted_list_front.truncate_front(n);
+
+            // assert
+            let n = n.min(array.len());
+            assert_eq!(array[..n], sorted_list.to_vec());
+            assert_eq!(array[array.len() - n..], sorted_list_front.to_vec());
+            assert_internals(&sorted_list);
+            assert_internals(&sorted_list_front);
+        }
+
+        let mut sorted_list = SortedList::from([1, 2, 3]);
+        sorted_list.truncate(0);
+        assert!(sorted_list.is_empty());
+        sorted_list.insert(4);
+        assert_eq!(Some(&4), sorted_list.first());
+    }
+
+    #[test]
+    fn dedup_test() {
+        // arrange
+        let (_, mut sorted_list, array) = random_list(50_000, 0..100);
+        let mut sorted_list_by_key = SortedList::from(array.clone());
+
+        // act
+        let runs: Vec<(&i32, usize)> = sorted_list.runs().collect();
+        let expected_runs: Vec<(&i32, usize)> = array
+            .chunk_by(|a, b| a == b)
+            .map(|run| (&run[0], run.len()))
+            .collect();
+        assert_eq!(expected_runs, runs);
+
+        sorted_list.dedup();
+        sorted_list_by_key.dedup_by_key(|x| x / 10);
+
+        // assert
+        let mut expected = array.clone();
+        expected.dedup();
+        assert_eq!(expected, sorted_list.to_vec());
+        assert_internals(&sorted_list);
+
+        let mut expected = array;
+        expected.dedup_by_key(|x| *x / 10);
+        assert_eq!(expected, sorted_list_by_key.to_vec());
+        assert_internals(&sorted_list_by_key);
+
+        let mut duplicates = SortedList::from([7; 10_000]);
+        assert_eq!(vec![(&7, 10_000)], duplicates.runs().collect::<Vec<_>>());
+        duplicates.dedup();
+        assert_eq!(vec![7], duplicates.to_vec());
+        assert_internals(&duplicates);
+    }
+
+    #[test]
+    #[allow(clippy::needless_range_loop)]
     fn flatten_test() {
         // arrange
         let mut rng = thread_rng();
@@ -904,7 +3733,7 @@ mod tests {
             }
         }
     }
-    
+
     #[test]
     fn break_case_insert_after_lst_has_been_clean() {
         let mut lst = SortedList::<usize>::new();
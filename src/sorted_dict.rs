@@ -0,0 +1,1020 @@
+use core::fmt;
+use std::{
+    borrow::Borrow,
+    fmt::Debug,
+    mem,
+    ops::{Bound, Index, RangeBounds},
+    slice,
+};
+
+use crate::{sorted_key_list::Keyed, Natural, SortedList};
+
+/// A sorted dictionary data structure, i.e. an ordered map backed by a SortedList of key-value pairs,
+/// like `SortedDict` of sortedcontainers.
+///
+/// Unlike `BTreeMap`, it offers positional access in O(log n) through [`SortedDict::peekitem`]
+/// and [`SortedDict::index_of`].
+///
+/// # Example
+///
+/// ```
+/// use sortedlist_rs::SortedDict;
+///
+/// let mut sorted_dict = SortedDict::new();
+/// sorted_dict.insert("bob", 30);
+/// sorted_dict.insert("alice", 25);
+/// sorted_dict.insert("carol", 35);
+///
+/// assert_eq!(Some(&25), sorted_dict.get(&"alice"));
+/// assert_eq!(Some((&"bob", &30)), sorted_dict.peekitem(1));
+/// assert_eq!(Some(2), sorted_dict.index_of(&"carol"));
+///
+/// *sorted_dict.entry("dave").or_insert(0) += 40;
+/// assert_eq!(vec![&"alice", &"bob", &"carol", &"dave"], sorted_dict.keys().collect::<Vec<_>>());
+/// ```
+pub struct SortedDict<K, V>
+where
+    K: Ord,
+{
+    _list: SortedList<Keyed<K, V>>,
+}
+
+/// Private method implementations
+impl<K, V> SortedDict<K, V>
+where
+    K: Ord,
+{
+    /// Returns (i,j) such that self._list._lists\[i]\[j] is the first pair whose key is not less than `key`,
+    /// or the past-the-end position of the last list. Returns (0,0) if the SortedDict is empty.
+    fn _key_position<Q>(&self, key: &Q) -> (usize, usize)
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        if self._list.is_empty() {
+            return (0, 0);
+        }
+
+        let lists = &self._list._lists;
+        let i = lists
+            .partition_point(|list| list.last().unwrap()._key.borrow() < key)
+            .min(lists.len() - 1);
+        let j = lists[i].partition_point(|keyed| keyed._key.borrow() < key);
+        (i, j)
+    }
+
+    /// Returns (i,j) such that self._list._lists\[i]\[j] is the pair whose key equals `key`, if any.
+    fn _position_of_key<Q>(&self, key: &Q) -> Option<(usize, usize)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (i, j) = self._key_position(key);
+        self._list
+            ._lists
+            .get(i)?
+            .get(j)
+            .filter(|keyed| keyed._key.borrow() == key)
+            .map(|_| (i, j))
+    }
+
+    /// Insert the pair `key`, `value` at the position (i,j) returned by `_key_position`, and returns its position afterwards.
+    fn _insert_at(&mut self, (i, j): (usize, usize), key: K, value: V) -> (usize, usize) {
+        let keyed = Keyed {
+            _key: key,
+            _element: value,
+        };
+        if self._list.is_empty() {
+            self._list.insert(keyed);
+            return (0, 0);
+        }
+        self._list._lists_insert_at(i, j, keyed)
+    }
+
+    /// Sort `iter` by key, keeping the last value of every key.
+    fn _sorted_batch<I>(iter: I) -> Vec<Keyed<K, V>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut pairs: Vec<(K, V)> = iter.into_iter().collect();
+        // latest pairs first, so that the stable sort and the dedup keep the last value of every key
+        pairs.reverse();
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        pairs.dedup_by(|a, b| a.0 == b.0);
+
+        pairs
+            .into_iter()
+            .map(|(key, value)| Keyed {
+                _key: key,
+                _element: value,
+            })
+            .collect()
+    }
+
+    /// Returns the number of keys smaller than `key`, or not greater than `key` if `inclusive`.
+    fn _bisect_key<Q>(&self, key: &Q, inclusive: bool) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._list.partition_point(|keyed| match inclusive {
+            true => keyed._key.borrow() <= key,
+            false => keyed._key.borrow() < key,
+        })
+    }
+
+    /// Retrieve a mutable reference of the value stored at self._list._lists\[i]\[j].
+    fn _value_mut(&mut self, (i, j): (usize, usize)) -> &mut V {
+        &mut self._list._lists[i][j]._element
+    }
+}
+
+/// Public method implementations
+impl<K, V> SortedDict<K, V>
+where
+    K: Ord,
+{
+    /// Creates an empty SortedDict.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict: SortedDict<i32, &str> = SortedDict::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            _list: SortedList::new(),
+        }
+    }
+
+    /// Inserts the pair `key`, `value` into the SortedDict.
+    /// If `key` was already present, its value is replaced and the old value is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let mut sorted_dict = SortedDict::new();
+    ///
+    /// assert_eq!(None, sorted_dict.insert(1, 'a'));
+    /// assert_eq!(Some('a'), sorted_dict.insert(1, 'b'));
+    /// assert_eq!(Some(&'b'), sorted_dict.get(&1));
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Returns a reference to the value of `key`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(1, 'a'), (2, 'b')]);
+    ///
+    /// assert_eq!(Some(&'b'), sorted_dict.get(&2));
+    /// assert_eq!(None, sorted_dict.get(&3));
+    ///
+    /// let names = SortedDict::from([("alice".to_string(), 25)]);
+    /// assert_eq!(Some(&25), names.get("alice"));
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._position_of_key(key)
+            .map(|(i, j)| &self._list._at(i, j)._element)
+    }
+
+    /// Returns a mutable reference to the value of `key`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let mut sorted_dict = SortedDict::from([(1, 'a'), (2, 'b')]);
+    ///
+    /// if let Some(value) = sorted_dict.get_mut(&2) {
+    ///     *value = 'c';
+    /// }
+    /// assert_eq!(Some(&'c'), sorted_dict.get(&2));
+    /// ```
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let position = self._position_of_key(key)?;
+        Some(self._value_mut(position))
+    }
+
+    /// Removes `key` from the SortedDict and returns its value, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let mut sorted_dict = SortedDict::from([(1, 'a'), (2, 'b')]);
+    ///
+    /// assert_eq!(Some('a'), sorted_dict.remove(&1));
+    /// assert_eq!(None, sorted_dict.remove(&1));
+    /// ```
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (i, j) = self._position_of_key(key)?;
+        Some(self._list._lists_remove(i, j)._element)
+    }
+
+    /// Gets the entry of `key` for in-place manipulation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let mut counts = SortedDict::new();
+    /// for word in ["b", "a", "b"] {
+    ///     *counts.entry(word).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(vec![(&"a", &1), (&"b", &2)], counts.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let position = self._key_position(&key);
+        let occupied = self
+            ._list
+            ._lists
+            .get(position.0)
+            .and_then(|list| list.get(position.1))
+            .is_some_and(|keyed| keyed._key == key);
+
+        match occupied {
+            true => Entry::Occupied(OccupiedEntry {
+                _dict: self,
+                _position: position,
+            }),
+            false => Entry::Vacant(VacantEntry {
+                _dict: self,
+                _key: key,
+                _position: position,
+            }),
+        }
+    }
+
+    /// Returns whether the SortedDict contains `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(1, 'a'), (2, 'b')]);
+    ///
+    /// assert!(sorted_dict.contains_key(&1));
+    /// assert!(!sorted_dict.contains_key(&3));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._position_of_key(key).is_some()
+    }
+
+    /// Returns the k-th (0-indexed) pair of the SortedDict ordered by key, if any,
+    /// like `peekitem` of sortedcontainers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+    ///
+    /// assert_eq!(Some((&2, &'b')), sorted_dict.peekitem(1));
+    /// assert_eq!(None, sorted_dict.peekitem(3));
+    /// ```
+    pub fn peekitem(&self, k: usize) -> Option<(&K, &V)> {
+        self._list
+            .get(k)
+            .map(|keyed| (&keyed._key, &keyed._element))
+    }
+
+    /// Removes the k-th (0-indexed) pair of the SortedDict ordered by key and returns it,
+    /// like `popitem` of sortedcontainers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let mut sorted_dict = SortedDict::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+    ///
+    /// assert_eq!((2, 'b'), sorted_dict.popitem(1));
+    /// assert_eq!(2, sorted_dict.len());
+    /// ```
+    pub fn popitem(&mut self, k: usize) -> (K, V) {
+        let keyed = self._list.remove(k);
+        (keyed._key, keyed._element)
+    }
+
+    /// Returns the index of `key` in the SortedDict, if present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(30, 'c'), (10, 'a'), (20, 'b')]);
+    ///
+    /// assert_eq!(Some(2), sorted_dict.index_of(&30));
+    /// assert_eq!(None, sorted_dict.index_of(&25));
+    /// ```
+    pub fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._position_of_key(key)
+            .map(|(i, j)| self._list._lists_offset(i) + j)
+    }
+
+    /// Returns the number of keys smaller than `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(30, 'c'), (10, 'a'), (20, 'b')]);
+    ///
+    /// assert_eq!(2, sorted_dict.bisect_key_left(&25));
+    /// ```
+    pub fn bisect_key_left<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._bisect_key(key, false)
+    }
+
+    /// Returns an iterator over the pairs whose key lies within `range`, ordered by key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+    /// let within: Vec<(&i32, &char)> = sorted_dict.range(2..4).collect();
+    ///
+    /// assert_eq!(vec![(&2, &'b'), (&3, &'c')], within);
+    /// ```
+    pub fn range<Q, R>(
+        &self,
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = (&K, &V)> + ExactSizeIterator
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(key) => self._bisect_key(key, false),
+            Bound::Excluded(key) => self._bisect_key(key, true),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(key) => self._bisect_key(key, true),
+            Bound::Excluded(key) => self._bisect_key(key, false),
+            Bound::Unbounded => self.len(),
+        };
+
+        // an inverted range yields nothing, like SortedList::range
+        self._list
+            .iter_range(start..end.max(start))
+            .map(|keyed| (&keyed._key, &keyed._element))
+    }
+
+    /// Returns an iterator over the pairs of the SortedDict, ordered by key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(2, 'b'), (1, 'a')]);
+    ///
+    /// assert_eq!(vec![(&1, &'a'), (&2, &'b')], sorted_dict.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> + ExactSizeIterator {
+        self._list
+            .iter()
+            .map(|keyed| (&keyed._key, &keyed._element))
+    }
+
+    /// Returns an iterator over the keys of the SortedDict, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(2, 'b'), (1, 'a')]);
+    ///
+    /// assert_eq!(vec![&1, &2], sorted_dict.keys().collect::<Vec<_>>());
+    /// ```
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator {
+        self._list.iter().map(|keyed| &keyed._key)
+    }
+
+    /// Returns an iterator over the values of the SortedDict, ordered by key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(2, 'b'), (1, 'a')]);
+    ///
+    /// assert_eq!(vec![&'a', &'b'], sorted_dict.values().collect::<Vec<_>>());
+    /// ```
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self._list.iter().map(|keyed| &keyed._element)
+    }
+
+    /// Returns an iterator over mutable references to the values of the SortedDict, ordered by key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let mut sorted_dict = SortedDict::from([(2, 20), (1, 10)]);
+    /// sorted_dict.values_mut().for_each(|value| *value += 1);
+    ///
+    /// assert_eq!(vec![&11, &21], sorted_dict.values().collect::<Vec<_>>());
+    /// ```
+    pub fn values_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut V> + ExactSizeIterator {
+        ValuesMut {
+            _lists: self._list._lists.iter_mut(),
+            _front: [].iter_mut(),
+            _back: [].iter_mut(),
+            _len: self._list._len,
+        }
+    }
+
+    /// Returns the number of pairs stored in the SortedDict.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict = SortedDict::from([(1, 'a'), (1, 'b')]);
+    /// assert_eq!(1, sorted_dict.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self._list.len()
+    }
+
+    /// Returns whether the SortedDict is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let sorted_dict: SortedDict<i32, char> = SortedDict::new();
+    /// assert!(sorted_dict.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self._list.is_empty()
+    }
+
+    /// Clears the SortedDict.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let mut sorted_dict = SortedDict::from([(1, 'a')]);
+    /// sorted_dict.clear();
+    ///
+    /// assert!(sorted_dict.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self._list.clear();
+    }
+}
+
+/// A view into a single entry of a SortedDict, which is either vacant or occupied.
+/// It is constructed from [`SortedDict::entry`].
+pub enum Entry<'a, K, V>
+where
+    K: Ord,
+{
+    Vacant(VacantEntry<'a, K, V>),
+    Occupied(OccupiedEntry<'a, K, V>),
+}
+
+/// A view into a vacant entry of a SortedDict.
+pub struct VacantEntry<'a, K, V>
+where
+    K: Ord,
+{
+    _dict: &'a mut SortedDict<K, V>,
+    _key: K,
+    _position: (usize, usize),
+}
+
+/// A view into an occupied entry of a SortedDict.
+pub struct OccupiedEntry<'a, K, V>
+where
+    K: Ord,
+{
+    _dict: &'a mut SortedDict<K, V>,
+    _position: (usize, usize),
+}
+
+impl<'a, K, V> Entry<'a, K, V>
+where
+    K: Ord,
+{
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Vacant(entry) => entry.key(),
+            Entry::Occupied(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns a mutable reference to the value of the entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let mut sorted_dict = SortedDict::from([(1, 10)]);
+    ///
+    /// *sorted_dict.entry(1).or_insert(0) += 1;
+    /// *sorted_dict.entry(2).or_insert(0) += 1;
+    ///
+    /// assert_eq!(vec![&11, &1], sorted_dict.values().collect::<Vec<_>>());
+    /// ```
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, and returns a mutable reference to the value of the entry.
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default()),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Inserts the default value if the entry is vacant, and returns a mutable reference to the value of the entry.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Calls `f` on the value of an occupied entry, and returns the entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedDict;
+    ///
+    /// let mut sorted_dict = SortedDict::from([(1, 10)]);
+    ///
+    /// sorted_dict.entry(1).and_modify(|value| *value *= 2).or_insert(0);
+    /// sorted_dict.entry(2).and_modify(|value| *value *= 2).or_insert(0);
+    ///
+    /// assert_eq!(vec![&20, &0], sorted_dict.values().collect::<Vec<_>>());
+    /// ```
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V>
+where
+    K: Ord,
+{
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        &self._key
+    }
+
+    /// Takes the ownership of the key.
+    pub fn into_key(self) -> K {
+        self._key
+    }
+
+    /// Inserts `value` under the key of the entry, and returns a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let position = self._dict._insert_at(self._position, self._key, value);
+        self._dict._value_mut(position)
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V>
+where
+    K: Ord,
+{
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        let (i, j) = self._position;
+        &self._dict._list._at(i, j)._key
+    }
+
+    /// Returns the index of the entry in the SortedDict.
+    pub fn index(&self) -> usize {
+        let (i, j) = self._position;
+        self._dict._list._lists_offset(i) + j
+    }
+
+    /// Returns a reference to the value of the entry.
+    pub fn get(&self) -> &V {
+        let (i, j) = self._position;
+        &self._dict._list._at(i, j)._element
+    }
+
+    /// Returns a mutable reference to the value of the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        self._dict._value_mut(self._position)
+    }
+
+    /// Converts the entry into a mutable reference to its value, bound to the lifetime of the SortedDict.
+    pub fn into_mut(self) -> &'a mut V {
+        self._dict._value_mut(self._position)
+    }
+
+    /// Replaces the value of the entry with `value`, and returns the old value.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the SortedDict, and returns its value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Removes the entry from the SortedDict, and returns its key and value.
+    pub fn remove_entry(self) -> (K, V) {
+        let (i, j) = self._position;
+        let keyed = self._dict._list._lists_remove(i, j);
+        (keyed._key, keyed._element)
+    }
+}
+
+/// Iterator over mutable references to the values of a SortedDict, walking the lists of the underlying SortedList.
+struct ValuesMut<'a, K, V> {
+    _lists: slice::IterMut<'a, Vec<Keyed<K, V>>>,
+    _front: slice::IterMut<'a, Keyed<K, V>>,
+    _back: slice::IterMut<'a, Keyed<K, V>>,
+    _len: usize,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(keyed) = self._front.next() {
+                self._len -= 1;
+                return Some(&mut keyed._element);
+            }
+
+            match self._lists.next() {
+                Some(list) => self._front = list.iter_mut(),
+                None => {
+                    let keyed = self._back.next()?;
+                    self._len -= 1;
+                    return Some(&mut keyed._element);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self._len, Some(self._len))
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(keyed) = self._back.next_back() {
+                self._len -= 1;
+                return Some(&mut keyed._element);
+            }
+
+            match self._lists.next_back() {
+                Some(list) => self._back = list.iter_mut(),
+                None => {
+                    let keyed = self._front.next_back()?;
+                    self._len -= 1;
+                    return Some(&mut keyed._element);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for ValuesMut<'a, K, V> {}
+
+impl<K, V> Default for SortedDict<K, V>
+where
+    K: Ord,
+{
+    /// Creates an empty SortedDict.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, Q> Index<&Q> for SortedDict<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    /// Access the value of the given key. Panics if the key is not present.
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("SortedDict: Key not found.")
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for SortedDict<K, V>
+where
+    K: Ord,
+{
+    /// Creates a SortedDict from an iterator of pairs. If a key appears more than once, the last value wins.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            _list: SortedList::_from_sorted(Self::_sorted_batch(iter), Natural),
+        }
+    }
+}
+
+impl<K, V, const N: usize> From<[(K, V); N]> for SortedDict<K, V>
+where
+    K: Ord,
+{
+    /// Creates a SortedDict from an array of pairs. If a key appears more than once, the last value wins.
+    fn from(array: [(K, V); N]) -> Self {
+        Self::from_iter(array)
+    }
+}
+
+impl<K, V> Extend<(K, V)> for SortedDict<K, V>
+where
+    K: Ord,
+{
+    /// Inserts a batch of pairs, replacing the values of existing keys. If a key appears more than once
+    /// in `iter`, the last value wins. New keys are merged in bulk, see the `Extend<T>` implementation of SortedList.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let mut fresh = vec![];
+        let lists = &mut self._list._lists;
+        let mut i = 0;
+
+        // the batch is sorted, so the list which may hold the key of the next pair never moves back
+        for keyed in Self::_sorted_batch(iter) {
+            i += lists[i..].partition_point(|list| list.last().is_some_and(|last| *last < keyed));
+            let present = lists
+                .get_mut(i)
+                .and_then(|list| list.binary_search(&keyed).ok().map(|j| &mut list[j]));
+            match present {
+                Some(present) => present._element = keyed._element,
+                None => fresh.push(keyed),
+            }
+        }
+
+        self._list._lists_insert_sorted(fresh);
+    }
+}
+
+impl<K, V> fmt::Debug for SortedDict<K, V>
+where
+    K: Ord + Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, ops::Bound};
+
+    use rand::{thread_rng, Rng};
+
+    use crate::{sorted_dict::Entry, SortedDict};
+
+    #[test]
+    fn random_tests() {
+        // arrange
+        let mut rng = thread_rng();
+        let pairs: Vec<(u32, u32)> = (0..10_000)
+            .map(|_| (rng.gen_range(0..20_000), rng.gen()))
+            .collect();
+        let mut sorted_dict = SortedDict::from_iter(pairs.clone());
+        let mut expected = BTreeMap::from_iter(pairs);
+        assert!(expected.iter().eq(sorted_dict.iter()));
+
+        for _ in 0..20_000 {
+            // act & assert
+            let key = rng.gen_range(0..20_000);
+            let value = rng.gen();
+            match rng.gen_range(0..4) {
+                0 => assert_eq!(expected.insert(key, value), sorted_dict.insert(key, value)),
+                1 => assert_eq!(expected.remove(&key), sorted_dict.remove(&key)),
+                2 => {
+                    if let Some(v) = expected.get_mut(&key) {
+                        *v = value;
+                    }
+                    if let Some(v) = sorted_dict.get_mut(&key) {
+                        *v = value;
+                    }
+                }
+                _ => {
+                    *expected.entry(key).or_default() += 1;
+                    *sorted_dict.entry(key).or_default() += 1;
+                }
+            }
+
+            assert_eq!(expected.get(&key), sorted_dict.get(&key));
+            let index = expected
+                .contains_key(&key)
+                .then(|| expected.range(..key).count());
+            assert_eq!(index, sorted_dict.index_of(&key));
+            let k = rng.gen_range(0..expected.len() + 10);
+            assert_eq!(expected.iter().nth(k), sorted_dict.peekitem(k));
+        }
+
+        // assert
+        assert_eq!(expected.len(), sorted_dict.len());
+        assert!(expected.keys().eq(sorted_dict.keys()));
+        assert!(expected.values().eq(sorted_dict.values()));
+        assert!(expected
+            .range(5_000..15_000)
+            .eq(sorted_dict.range(5_000..15_000)));
+    }
+
+    #[test]
+    fn borrowed_key_test() {
+        // arrange
+        let mut sorted_dict: SortedDict<String, usize> = ["b", "d", "a", "c"]
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (key.to_string(), i))
+            .collect();
+
+        // act & assert
+        assert_eq!(Some(&1), sorted_dict.get("d"));
+        assert!(sorted_dict.contains_key("a"));
+        assert!(!sorted_dict.contains_key("e"));
+        assert_eq!(Some(2), sorted_dict.index_of("c"));
+        assert_eq!(1, sorted_dict.bisect_key_left("aa"));
+        assert_eq!(0, sorted_dict["b"]);
+        *sorted_dict.get_mut("c").unwrap() += 10;
+        assert_eq!(
+            vec![(&"b".to_string(), &0), (&"c".to_string(), &13)],
+            sorted_dict
+                .range::<str, _>((Bound::Included("b"), Bound::Excluded("d")))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            0,
+            sorted_dict
+                .range::<str, _>((Bound::Included("d"), Bound::Excluded("b")))
+                .len()
+        );
+        assert_eq!(Some(2), sorted_dict.remove("a"));
+        assert_eq!(None, sorted_dict.remove("a"));
+        assert_eq!(3, sorted_dict.len());
+    }
+
+    #[test]
+    fn values_mut_test() {
+        // arrange
+        let mut sorted_dict: SortedDict<u32, u32> = (0..5_000).map(|x| (x, x)).collect();
+        let mut expected: Vec<u32> = (0..5_000).collect();
+
+        // act
+        let mut values = sorted_dict.values_mut();
+        assert_eq!(5_000, values.len());
+        *values.next().unwrap() += 1;
+        *values.next_back().unwrap() += 1;
+        assert_eq!(4_998, values.len());
+        values.rev().step_by(2).for_each(|value| *value *= 2);
+        expected[0] += 1;
+        expected[4_999] += 1;
+        expected[1..4_999]
+            .iter_mut()
+            .rev()
+            .step_by(2)
+            .for_each(|value| *value *= 2);
+
+        // assert
+        assert!(expected.iter().eq(sorted_dict.values()));
+    }
+
+    #[test]
+    fn entry_test() {
+        // arrange
+        let mut sorted_dict: SortedDict<u32, u32> = (0..5_000).map(|x| (2 * x, x)).collect();
+
+        // act
+        for key in 0..10_000 {
+            match sorted_dict.entry(key) {
+                Entry::Occupied(mut entry) => {
+                    assert_eq!(key as usize, entry.index());
+                    assert_eq!(key / 2, entry.insert(key));
+                }
+                Entry::Vacant(entry) => {
+                    assert_eq!(key, *entry.insert(key));
+                }
+            }
+        }
+        for key in (0..10_000).step_by(3) {
+            if let Entry::Occupied(entry) = sorted_dict.entry(key) {
+                assert_eq!((key, key), entry.remove_entry());
+            }
+        }
+        sorted_dict.values_mut().for_each(|value| *value += 1);
+
+        // assert
+        let expected: Vec<(u32, u32)> = (0..10_000)
+            .filter(|key| key % 3 != 0)
+            .map(|key| (key, key + 1))
+            .collect();
+        assert!(expected
+            .iter()
+            .map(|(key, value)| (key, value))
+            .eq(sorted_dict.iter()));
+    }
+
+    #[test]
+    fn extend_test() {
+        // arrange
+        let mut rng = thread_rng();
+        let mut sorted_dict: SortedDict<i32, i32> = SortedDict::new();
+        let mut btree_map: BTreeMap<i32, i32> = BTreeMap::new();
+
+        // act
+        for _ in 0..20 {
+            let batch: Vec<(i32, i32)> = (0..rng.gen_range(0..5_000))
+                .map(|_| (rng.gen_range(0..20_000), rng.gen_range(0..100)))
+                .collect();
+            sorted_dict.extend(batch.clone());
+            btree_map.extend(batch);
+
+            // assert
+            assert_eq!(btree_map.len(), sorted_dict.len());
+            assert!(btree_map.iter().eq(sorted_dict.iter()));
+        }
+    }
+}
@@ -0,0 +1,608 @@
+use core::fmt;
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::Debug,
+    ops::{Index, Range, RangeBounds},
+};
+
+use crate::SortedList;
+
+/// An element of a SortedKeyList stored together with its cached key.
+/// It is ordered by the key only, so that it can be looked up through `Borrow<K>`.
+pub(crate) struct Keyed<K, T> {
+    pub(crate) _key: K,
+    pub(crate) _element: T,
+}
+
+impl<K, T> PartialEq for Keyed<K, T>
+where
+    K: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self._key == other._key
+    }
+}
+
+impl<K, T> Eq for Keyed<K, T> where K: Ord {}
+
+impl<K, T> PartialOrd for Keyed<K, T>
+where
+    K: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K, T> Ord for Keyed<K, T>
+where
+    K: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self._key.cmp(&other._key)
+    }
+}
+
+impl<K, T> Borrow<K> for Keyed<K, T> {
+    fn borrow(&self) -> &K {
+        &self._key
+    }
+}
+
+/// A sorted list ordered by a key extraction function, like `SortedKeyList` of sortedcontainers.
+///
+/// The key of every element is computed once on insertion and cached alongside the element,
+/// so expensive projections are not recomputed during binary searches.
+/// Elements with equal keys are kept in insertion order.
+///
+/// # Example
+///
+/// ```
+/// use sortedlist_rs::SortedKeyList;
+///
+/// let mut sorted_key_list = SortedKeyList::new(|x: &(&str, i32)| x.1);
+/// sorted_key_list.insert(("bob", 30));
+/// sorted_key_list.insert(("alice", 25));
+/// sorted_key_list.insert(("carol", 35));
+///
+/// assert_eq!(("alice", 25), sorted_key_list[0]);
+/// assert_eq!(1, sorted_key_list.bisect_key_left(&30));
+///
+/// let removed = sorted_key_list.remove_key(&35);
+/// assert_eq!(Some(("carol", 35)), removed);
+/// ```
+pub struct SortedKeyList<T, K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    _list: SortedList<Keyed<K, T>>,
+    _key: F,
+}
+
+impl<T, K, F> SortedKeyList<T, K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    /// Wrap `element` with its key.
+    fn _keyed(&self, element: T) -> Keyed<K, T> {
+        Keyed {
+            _key: (self._key)(&element),
+            _element: element,
+        }
+    }
+
+    /// Creates an empty SortedKeyList ordered by `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::new(|x: &i32| -x);
+    /// assert!(sorted_key_list.is_empty());
+    /// ```
+    pub fn new(key: F) -> Self {
+        Self {
+            _list: SortedList::new(),
+            _key: key,
+        }
+    }
+
+    /// Creates a SortedKeyList ordered by `key` from the elements of `iter`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([1, 3, 2], |x: &i32| -x);
+    /// assert_eq!(vec![&3, &2, &1], sorted_key_list.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn from_iter_with_key<I>(iter: I, key: F) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut sorted_key_list = Self::new(key);
+        sorted_key_list.extend(iter);
+        sorted_key_list
+    }
+
+    /// Insert `element` into the SortedKeyList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let mut sorted_key_list = SortedKeyList::new(|x: &i32| -x);
+    /// sorted_key_list.insert(1);
+    /// sorted_key_list.insert(5);
+    ///
+    /// assert_eq!(5, sorted_key_list[0]);
+    /// ```
+    pub fn insert(&mut self, element: T) {
+        let keyed = self._keyed(element);
+        self._list.insert(keyed);
+    }
+
+    /// Find the k-th smallest (0-indexed) element in the SortedKeyList, by key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([10, 2, 3], |x: &i32| -x);
+    /// assert_eq!(&3, sorted_key_list.kth_smallest(1));
+    /// ```
+    pub fn kth_smallest(&self, k: usize) -> &T {
+        &self._list.kth_smallest(k)._element
+    }
+
+    /// Returns the element for the given index in the SortedKeyList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([10, 2, 3], |x: &i32| -x);
+    /// assert_eq!(Some(&2), sorted_key_list.get(2));
+    /// assert_eq!(None, sorted_key_list.get(3));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self._list.get(index).map(|keyed| &keyed._element)
+    }
+
+    /// Returns the element with the smallest key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([10, 2, 3], |x: &i32| -x);
+    /// assert_eq!(Some(&10), sorted_key_list.first());
+    /// ```
+    pub fn first(&self) -> Option<&T> {
+        self._list.first().map(|keyed| &keyed._element)
+    }
+
+    /// Returns the element with the largest key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([10, 2, 3], |x: &i32| -x);
+    /// assert_eq!(Some(&2), sorted_key_list.last());
+    /// ```
+    pub fn last(&self) -> Option<&T> {
+        self._list.last().map(|keyed| &keyed._element)
+    }
+
+    /// Pops the k-th smallest (0-indexed) element from the SortedKeyList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let mut sorted_key_list = SortedKeyList::from_iter_with_key([10, 2, 3], |x: &i32| -x);
+    /// assert_eq!(3, sorted_key_list.remove(1));
+    /// ```
+    pub fn remove(&mut self, k: usize) -> T {
+        self._list.remove(k)._element
+    }
+
+    /// Removes the element with the smallest key and returns it, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let mut sorted_key_list = SortedKeyList::from_iter_with_key([10, 2, 3], |x: &i32| -x);
+    /// assert_eq!(Some(10), sorted_key_list.pop_first());
+    /// ```
+    pub fn pop_first(&mut self) -> Option<T> {
+        self._list.pop_first().map(|keyed| keyed._element)
+    }
+
+    /// Removes the element with the largest key and returns it, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let mut sorted_key_list = SortedKeyList::from_iter_with_key([10, 2, 3], |x: &i32| -x);
+    /// assert_eq!(Some(2), sorted_key_list.pop_last());
+    /// ```
+    pub fn pop_last(&mut self) -> Option<T> {
+        self._list.pop_last().map(|keyed| keyed._element)
+    }
+
+    /// Removes one element whose key equals `key` and returns it, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let mut sorted_key_list = SortedKeyList::from_iter_with_key([(1, 'a'), (2, 'b')], |x| x.0);
+    ///
+    /// assert_eq!(Some((2, 'b')), sorted_key_list.remove_key(&2));
+    /// assert_eq!(None, sorted_key_list.remove_key(&2));
+    /// ```
+    pub fn remove_key(&mut self, key: &K) -> Option<T> {
+        self._list
+            .remove_value::<K>(key)
+            .map(|keyed| keyed._element)
+    }
+
+    /// Removes every element whose key equals `key`. Returns the number of removed elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let mut sorted_key_list =
+    ///     SortedKeyList::from_iter_with_key([(1, 'a'), (2, 'b'), (2, 'c')], |x| x.0);
+    ///
+    /// assert_eq!(2, sorted_key_list.remove_all_key(&2));
+    /// assert_eq!(1, sorted_key_list.len());
+    /// ```
+    pub fn remove_all_key(&mut self, key: &K) -> usize {
+        self._list.remove_all::<K>(key)
+    }
+
+    /// Returns the index of the first element whose key is not less than `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list =
+    ///     SortedKeyList::from_iter_with_key([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')], |x| x.0);
+    ///
+    /// assert_eq!(1, sorted_key_list.bisect_key_left(&2));
+    /// ```
+    pub fn bisect_key_left(&self, key: &K) -> usize {
+        self._list.bisect_left::<K>(key)
+    }
+
+    /// Returns the index of the first element whose key is greater than `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list =
+    ///     SortedKeyList::from_iter_with_key([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')], |x| x.0);
+    ///
+    /// assert_eq!(3, sorted_key_list.bisect_key_right(&2));
+    /// ```
+    pub fn bisect_key_right(&self, key: &K) -> usize {
+        self._list.bisect_right::<K>(key)
+    }
+
+    /// Returns the index of the first element whose key is not less than the key of `element`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([(1, 'a'), (3, 'b')], |x| x.0);
+    ///
+    /// assert_eq!(1, sorted_key_list.bisect_left(&(2, 'z')));
+    /// ```
+    pub fn bisect_left(&self, element: &T) -> usize {
+        self.bisect_key_left(&(self._key)(element))
+    }
+
+    /// Returns the index of the first element whose key is greater than the key of `element`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([(1, 'a'), (3, 'b')], |x| x.0);
+    ///
+    /// assert_eq!(2, sorted_key_list.bisect_right(&(3, 'z')));
+    /// ```
+    pub fn bisect_right(&self, element: &T) -> usize {
+        self.bisect_key_right(&(self._key)(element))
+    }
+
+    /// Returns the range of indices holding elements whose key equals `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list =
+    ///     SortedKeyList::from_iter_with_key([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')], |x| x.0);
+    ///
+    /// assert_eq!(1..3, sorted_key_list.equal_range_key(&2));
+    /// ```
+    pub fn equal_range_key(&self, key: &K) -> Range<usize> {
+        self._list.equal_range::<K>(key)
+    }
+
+    /// Returns whether the SortedKeyList contains an element whose key equals `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([(1, 'a'), (3, 'b')], |x| x.0);
+    ///
+    /// assert!(sorted_key_list.contains_key(&3));
+    /// assert!(!sorted_key_list.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self._list.contains::<K>(key)
+    }
+
+    /// Returns the number of elements whose key equals `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([(1, 'a'), (1, 'b')], |x| x.0);
+    ///
+    /// assert_eq!(2, sorted_key_list.count_key(&1));
+    /// ```
+    pub fn count_key(&self, key: &K) -> usize {
+        self._list.count::<K>(key)
+    }
+
+    /// Returns an iterator over the elements whose key falls within the key range `range`,
+    /// like `irange_key` of sortedcontainers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list =
+    ///     SortedKeyList::from_iter_with_key([(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')], |x| x.0);
+    /// let within: Vec<char> = sorted_key_list.irange_key(2..4).map(|x| x.1).collect();
+    ///
+    /// assert_eq!(vec!['b', 'c'], within);
+    /// ```
+    pub fn irange_key<R>(&self, range: R) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator
+    where
+        R: RangeBounds<K>,
+    {
+        self._list.range::<K, R>(range).map(|keyed| &keyed._element)
+    }
+
+    /// Returns the number of elements stored in the SortedKeyList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([1, 2], |x: &i32| -x);
+    /// assert_eq!(2, sorted_key_list.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self._list.len()
+    }
+
+    /// Returns whether the SortedKeyList is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::new(|x: &i32| -x);
+    /// assert!(sorted_key_list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self._list.is_empty()
+    }
+
+    /// Clears the SortedKeyList.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let mut sorted_key_list = SortedKeyList::from_iter_with_key([1, 2], |x: &i32| -x);
+    /// sorted_key_list.clear();
+    ///
+    /// assert!(sorted_key_list.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self._list.clear();
+    }
+
+    /// Returns an iterator over the elements of the SortedKeyList, ordered by key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedKeyList;
+    ///
+    /// let sorted_key_list = SortedKeyList::from_iter_with_key([1, 3, 2], |x: &i32| -x);
+    /// let elements: Vec<&i32> = sorted_key_list.iter().rev().collect();
+    ///
+    /// assert_eq!(vec![&1, &2, &3], elements);
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self._list.iter().map(|keyed| &keyed._element)
+    }
+}
+
+impl<T, K, F> Extend<T> for SortedKeyList<T, K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    /// Inserts a batch of elements, computing every key once. See the `Extend<T>` implementation of SortedList.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let key = &self._key;
+        self._list.extend(iter.into_iter().map(|element| Keyed {
+            _key: key(&element),
+            _element: element,
+        }));
+    }
+}
+
+impl<T, K, F> Index<usize> for SortedKeyList<T, K, F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    type Output = T;
+
+    /// Access the SortedKeyList for the given index.
+    fn index(&self, index: usize) -> &Self::Output {
+        self.kth_smallest(index)
+    }
+}
+
+impl<T, K, F> fmt::Debug for SortedKeyList<T, K, F>
+where
+    T: Debug,
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, Rng};
+
+    use crate::SortedKeyList;
+
+    #[derive(Debug, PartialEq)]
+    struct Player {
+        name: String,
+        score: u32,
+    }
+
+    #[test]
+    fn random_tests() {
+        // arrange
+        let mut rng = thread_rng();
+        let mut sorted_key_list = SortedKeyList::new(|p: &Player| p.score);
+        let mut scores: Vec<u32> = vec![];
+
+        for i in 0..20_000 {
+            let score = rng.gen_range(0..1_000);
+            sorted_key_list.insert(Player {
+                name: format!("player-{}", i),
+                score,
+            });
+            scores.push(score);
+        }
+        scores.sort();
+
+        // assert
+        assert!(sorted_key_list
+            .iter()
+            .map(|p| p.score)
+            .eq(scores.iter().copied()));
+        for _ in 0..1_000 {
+            let score = rng.gen_range(0..1_000);
+            let left = scores.partition_point(|s| *s < score);
+            let right = scores.partition_point(|s| *s <= score);
+            assert_eq!(left, sorted_key_list.bisect_key_left(&score));
+            assert_eq!(right, sorted_key_list.bisect_key_right(&score));
+            assert_eq!(right - left, sorted_key_list.count_key(&score));
+            assert!(sorted_key_list
+                .irange_key(score..=score)
+                .all(|p| p.score == score));
+            assert_eq!(
+                right - left,
+                sorted_key_list.irange_key(score..=score).len()
+            );
+        }
+
+        // act
+        for _ in 0..1_000 {
+            let score = rng.gen_range(0..1_000);
+            let removed = sorted_key_list.remove_key(&score);
+            match scores.binary_search(&score) {
+                Ok(i) => {
+                    scores.remove(i);
+                    assert_eq!(Some(score), removed.map(|p| p.score));
+                }
+                Err(_) => assert_eq!(None, removed),
+            }
+        }
+        let score = scores[scores.len() / 2];
+        let expected = scores.iter().filter(|s| **s == score).count();
+        assert_eq!(expected, sorted_key_list.remove_all_key(&score));
+        scores.retain(|s| *s != score);
+
+        // assert
+        assert_eq!(scores.len(), sorted_key_list.len());
+        assert!(sorted_key_list
+            .iter()
+            .map(|p| p.score)
+            .eq(scores.iter().copied()));
+    }
+
+    #[test]
+    fn key_is_computed_once_per_element() {
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        let mut sorted_key_list = SortedKeyList::new(|x: &i32| {
+            calls.set(calls.get() + 1);
+            -x
+        });
+
+        sorted_key_list.extend(0..5_000);
+        assert_eq!(5_000, calls.get());
+
+        sorted_key_list.bisect_key_left(&-10);
+        sorted_key_list.remove_key(&-10);
+        assert_eq!(5_000, calls.get());
+        assert_eq!(Some(&4_999), sorted_key_list.first());
+    }
+}
@@ -0,0 +1,475 @@
+use core::fmt;
+use std::{
+    fmt::Debug,
+    ops::{Range, RangeBounds},
+};
+
+use crate::{sorted_key_list::Keyed, Natural, SortedList};
+
+/// A sorted multimap data structure, i.e. an ordered map which allows duplicated keys.
+///
+/// Values of the same key are kept in insertion order, and the pairs can be accessed by position
+/// in the flattened `(K, V)` sequence in O(log n).
+///
+/// # Example
+///
+/// ```
+/// use sortedlist_rs::SortedMultiMap;
+///
+/// let mut sorted_multimap = SortedMultiMap::new();
+/// sorted_multimap.insert(20, "b");
+/// sorted_multimap.insert(10, "a");
+/// sorted_multimap.insert(20, "c");
+///
+/// assert_eq!(vec![&"b", &"c"], sorted_multimap.get_all(&20).collect::<Vec<_>>());
+/// assert_eq!(2, sorted_multimap.count(&20));
+/// assert_eq!(Some((&20, &"c")), sorted_multimap.get(2));
+///
+/// assert_eq!(vec!["b", "c"], sorted_multimap.remove_all(&20));
+/// assert_eq!(1, sorted_multimap.len());
+/// ```
+pub struct SortedMultiMap<K, V>
+where
+    K: Ord,
+{
+    _list: SortedList<Keyed<K, V>>,
+}
+
+impl<K, V> SortedMultiMap<K, V>
+where
+    K: Ord,
+{
+    /// Creates an empty SortedMultiMap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap: SortedMultiMap<i32, &str> = SortedMultiMap::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            _list: SortedList::new(),
+        }
+    }
+
+    /// Inserts the pair `key`, `value` into the SortedMultiMap, after the values already stored under `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let mut sorted_multimap = SortedMultiMap::new();
+    /// sorted_multimap.insert(1, 'a');
+    /// sorted_multimap.insert(1, 'b');
+    ///
+    /// assert_eq!(vec![&'a', &'b'], sorted_multimap.get_all(&1).collect::<Vec<_>>());
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) {
+        self._list.insert(Keyed {
+            _key: key,
+            _element: value,
+        });
+    }
+
+    /// Returns an iterator over the values of `key`, in insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(1, 'a'), (2, 'b'), (1, 'c')]);
+    ///
+    /// assert_eq!(vec![&'a', &'c'], sorted_multimap.get_all(&1).collect::<Vec<_>>());
+    /// assert_eq!(0, sorted_multimap.get_all(&3).len());
+    /// ```
+    pub fn get_all(&self, key: &K) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self._list
+            .range::<K, _>(key..=key)
+            .map(|keyed| &keyed._element)
+    }
+
+    /// Removes every pair whose key equals `key`, and returns their values in insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let mut sorted_multimap = SortedMultiMap::from([(1, 'a'), (2, 'b'), (1, 'c')]);
+    ///
+    /// assert_eq!(vec!['a', 'c'], sorted_multimap.remove_all(&1));
+    /// assert!(sorted_multimap.remove_all(&1).is_empty());
+    /// ```
+    pub fn remove_all(&mut self, key: &K) -> Vec<V> {
+        self._list
+            .drain_range_by_value::<K, _>(key..=key)
+            .map(|keyed| keyed._element)
+            .collect()
+    }
+
+    /// Returns the number of values stored under `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(1, 'a'), (2, 'b'), (1, 'c')]);
+    ///
+    /// assert_eq!(2, sorted_multimap.count(&1));
+    /// assert_eq!(0, sorted_multimap.count(&3));
+    /// ```
+    pub fn count(&self, key: &K) -> usize {
+        self._list.count::<K>(key)
+    }
+
+    /// Returns whether the SortedMultiMap contains `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(1, 'a')]);
+    ///
+    /// assert!(sorted_multimap.contains_key(&1));
+    /// assert!(!sorted_multimap.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self._list.contains::<K>(key)
+    }
+
+    /// Returns the pair at `index` in the flattened `(K, V)` sequence, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(2, 'b'), (1, 'a'), (1, 'c')]);
+    ///
+    /// assert_eq!(Some((&1, &'c')), sorted_multimap.get(1));
+    /// assert_eq!(None, sorted_multimap.get(3));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<(&K, &V)> {
+        self._list
+            .get(index)
+            .map(|keyed| (&keyed._key, &keyed._element))
+    }
+
+    /// Removes the pair at `index` in the flattened `(K, V)` sequence and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let mut sorted_multimap = SortedMultiMap::from([(2, 'b'), (1, 'a'), (1, 'c')]);
+    ///
+    /// assert_eq!((1, 'c'), sorted_multimap.remove(1));
+    /// assert_eq!(2, sorted_multimap.len());
+    /// ```
+    pub fn remove(&mut self, index: usize) -> (K, V) {
+        let keyed = self._list.remove(index);
+        (keyed._key, keyed._element)
+    }
+
+    /// Returns the index range of the pairs whose key equals `key` in the flattened `(K, V)` sequence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]);
+    ///
+    /// assert_eq!(1..3, sorted_multimap.equal_range(&2));
+    /// assert_eq!(4..4, sorted_multimap.equal_range(&5));
+    /// ```
+    pub fn equal_range(&self, key: &K) -> Range<usize> {
+        self._list.equal_range::<K>(key)
+    }
+
+    /// Returns the index of the first pair whose key is not less than `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]);
+    ///
+    /// assert_eq!(1, sorted_multimap.bisect_key_left(&2));
+    /// ```
+    pub fn bisect_key_left(&self, key: &K) -> usize {
+        self._list.bisect_left::<K>(key)
+    }
+
+    /// Returns the index of the first pair whose key is greater than `key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]);
+    ///
+    /// assert_eq!(3, sorted_multimap.bisect_key_right(&2));
+    /// ```
+    pub fn bisect_key_right(&self, key: &K) -> usize {
+        self._list.bisect_right::<K>(key)
+    }
+
+    /// Returns an iterator over the pairs whose key lies within `range`, ordered by key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(1, 'a'), (2, 'b'), (2, 'c'), (3, 'd')]);
+    /// let within: Vec<(&i32, &char)> = sorted_multimap.range(2..).collect();
+    ///
+    /// assert_eq!(vec![(&2, &'b'), (&2, &'c'), (&3, &'d')], within);
+    /// ```
+    pub fn range<R>(
+        &self,
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = (&K, &V)> + ExactSizeIterator
+    where
+        R: RangeBounds<K>,
+    {
+        self._list
+            .range::<K, R>(range)
+            .map(|keyed| (&keyed._key, &keyed._element))
+    }
+
+    /// Returns an iterator over the pairs of the SortedMultiMap, ordered by key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(2, 'b'), (1, 'a'), (2, 'c')]);
+    /// let pairs: Vec<(&i32, &char)> = sorted_multimap.iter().collect();
+    ///
+    /// assert_eq!(vec![(&1, &'a'), (&2, &'b'), (&2, &'c')], pairs);
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> + ExactSizeIterator {
+        self._list
+            .iter()
+            .map(|keyed| (&keyed._key, &keyed._element))
+    }
+
+    /// Returns an iterator over the keys of the SortedMultiMap, repeated once per value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(2, 'b'), (1, 'a'), (2, 'c')]);
+    ///
+    /// assert_eq!(vec![&1, &2, &2], sorted_multimap.keys().collect::<Vec<_>>());
+    /// ```
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator {
+        self._list.iter().map(|keyed| &keyed._key)
+    }
+
+    /// Returns an iterator over the values of the SortedMultiMap, ordered by key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(2, 'b'), (1, 'a'), (2, 'c')]);
+    ///
+    /// assert_eq!(vec![&'a', &'b', &'c'], sorted_multimap.values().collect::<Vec<_>>());
+    /// ```
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self._list.iter().map(|keyed| &keyed._element)
+    }
+
+    /// Returns the number of pairs stored in the SortedMultiMap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap = SortedMultiMap::from([(1, 'a'), (1, 'b')]);
+    /// assert_eq!(2, sorted_multimap.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self._list.len()
+    }
+
+    /// Returns whether the SortedMultiMap is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let sorted_multimap: SortedMultiMap<i32, char> = SortedMultiMap::new();
+    /// assert!(sorted_multimap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self._list.is_empty()
+    }
+
+    /// Clears the SortedMultiMap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedMultiMap;
+    ///
+    /// let mut sorted_multimap = SortedMultiMap::from([(1, 'a')]);
+    /// sorted_multimap.clear();
+    ///
+    /// assert!(sorted_multimap.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self._list.clear();
+    }
+}
+
+impl<K, V> Default for SortedMultiMap<K, V>
+where
+    K: Ord,
+{
+    /// Creates an empty SortedMultiMap.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for SortedMultiMap<K, V>
+where
+    K: Ord,
+{
+    /// Creates a SortedMultiMap from an iterator of pairs. Values of the same key keep their order in `iter`.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut sorted: Vec<Keyed<K, V>> = iter
+            .into_iter()
+            .map(|(key, value)| Keyed {
+                _key: key,
+                _element: value,
+            })
+            .collect();
+        sorted.sort();
+        Self {
+            _list: SortedList::_from_sorted(sorted, Natural),
+        }
+    }
+}
+
+impl<K, V, const N: usize> From<[(K, V); N]> for SortedMultiMap<K, V>
+where
+    K: Ord,
+{
+    /// Creates a SortedMultiMap from an array of pairs. Values of the same key keep their order in `array`.
+    fn from(array: [(K, V); N]) -> Self {
+        Self::from_iter(array)
+    }
+}
+
+impl<K, V> Extend<(K, V)> for SortedMultiMap<K, V>
+where
+    K: Ord,
+{
+    /// Inserts a batch of pairs, after the values already stored under their keys.
+    /// See the `Extend<T>` implementation of SortedList.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self._list
+            .extend(iter.into_iter().map(|(key, value)| Keyed {
+                _key: key,
+                _element: value,
+            }));
+    }
+}
+
+impl<K, V> fmt::Debug for SortedMultiMap<K, V>
+where
+    K: Ord + Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use rand::{thread_rng, Rng};
+
+    use crate::SortedMultiMap;
+
+    #[test]
+    fn random_tests() {
+        // arrange
+        let mut rng = thread_rng();
+        let pairs: Vec<(u32, usize)> = (0..10_000).map(|i| (rng.gen_range(0..1_000), i)).collect();
+        let mut sorted_multimap = SortedMultiMap::from_iter(pairs.clone());
+        let mut expected: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
+        for (key, value) in pairs {
+            expected.entry(key).or_default().push(value);
+        }
+
+        // act
+        for i in 10_000..20_000 {
+            let key = rng.gen_range(0..1_000);
+            sorted_multimap.insert(key, i);
+            expected.entry(key).or_default().push(i);
+        }
+        let batch: Vec<(u32, usize)> = (20_000..25_000)
+            .map(|i| (rng.gen_range(0..1_000), i))
+            .collect();
+        sorted_multimap.extend(batch.clone());
+        for (key, value) in batch {
+            expected.entry(key).or_default().push(value);
+        }
+
+        // assert
+        let flattened: Vec<(&u32, &usize)> = expected
+            .iter()
+            .flat_map(|(key, values)| values.iter().map(move |value| (key, value)))
+            .collect();
+        assert_eq!(flattened.len(), sorted_multimap.len());
+        assert!(flattened.iter().copied().eq(sorted_multimap.iter()));
+        for _ in 0..1_000 {
+            let index = rng.gen_range(0..flattened.len() + 10);
+            assert_eq!(flattened.get(index).copied(), sorted_multimap.get(index));
+        }
+
+        for _ in 0..500 {
+            // act & assert
+            let key = rng.gen_range(0..1_000);
+            let values = expected.remove(&key).unwrap_or_default();
+            assert_eq!(values.len(), sorted_multimap.count(&key));
+            assert!(values.iter().eq(sorted_multimap.get_all(&key)));
+            assert_eq!(values, sorted_multimap.remove_all(&key));
+            assert!(!sorted_multimap.contains_key(&key));
+        }
+
+        // assert
+        assert_eq!(
+            expected.values().map(Vec::len).sum::<usize>(),
+            sorted_multimap.len()
+        );
+        assert!(expected.values().flatten().eq(sorted_multimap.values()));
+    }
+}
@@ -0,0 +1,736 @@
+use core::fmt;
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::Debug,
+    iter::Peekable,
+    ops::{Index, RangeBounds},
+};
+
+use crate::{IntoIter, Iter, Natural, SortedList};
+
+/// A sorted set data structure, i.e. a SortedList without duplicated elements.
+///
+/// Unlike `BTreeSet`, it offers positional access in O(log n) through [`SortedSet::kth`]
+/// and [`SortedSet::index_of`].
+///
+/// # Example
+///
+/// ```
+/// use sortedlist_rs::SortedSet;
+///
+/// let mut sorted_set = SortedSet::from([90, 19, 25, 19]);
+/// assert_eq!(3, sorted_set.len());
+///
+/// assert!(sorted_set.insert(1));
+/// assert!(!sorted_set.insert(25));
+///
+/// assert_eq!(Some(&19), sorted_set.kth(1));
+/// assert_eq!(Some(2), sorted_set.index_of(&25));
+///
+/// let other = SortedSet::from([1, 2, 90]);
+/// let intersection = sorted_set.intersection(&other);
+/// assert_eq!(vec![&1, &90], intersection.iter().collect::<Vec<_>>());
+/// ```
+pub struct SortedSet<T>
+where
+    T: Ord,
+{
+    _list: SortedList<T>,
+}
+
+/// Private method implementations
+impl<T> SortedSet<T>
+where
+    T: Ord,
+{
+    /// Walk `self` and `other` in sorted order, cloning every element for which `keep(in_self, in_other)` holds
+    /// into a new balanced SortedSet.
+    fn _merge<F>(&self, other: &Self, keep: F) -> Self
+    where
+        T: Clone,
+        F: Fn(bool, bool) -> bool,
+    {
+        let mut merged = vec![];
+        for (element, in_self, in_other) in MergeWalk::new(self.iter(), other.iter()) {
+            if keep(in_self, in_other) {
+                merged.push(element.clone());
+            }
+        }
+
+        Self {
+            _list: SortedList::_from_sorted(merged, Natural),
+        }
+    }
+}
+
+/// Public method implementations
+impl<T> SortedSet<T>
+where
+    T: Ord,
+{
+    /// Creates an empty SortedSet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set: SortedSet<i32> = SortedSet::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            _list: SortedList::new(),
+        }
+    }
+
+    /// Inserts `element` into the SortedSet.
+    /// Returns whether it was newly inserted, i.e. false if an equal element was already present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let mut sorted_set = SortedSet::new();
+    ///
+    /// assert!(sorted_set.insert(10));
+    /// assert!(!sorted_set.insert(10));
+    /// assert_eq!(1, sorted_set.len());
+    /// ```
+    pub fn insert(&mut self, element: T) -> bool {
+        if self._list.is_empty() {
+            self._list.insert(element);
+            return true;
+        }
+
+        // an element equal to `element` can only sit right before its insert position in this list
+        let i = self._list._bisect_right_lists(&element);
+        let j = self._list._lists[i].partition_point(|x| *x <= element);
+        if j > 0 && self._list._lists[i][j - 1] == element {
+            return false;
+        }
+        self._list._lists_insert_at(i, j, element);
+        true
+    }
+
+    /// Removes the element equal to `element` from the SortedSet. Returns whether it was present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let mut sorted_set = SortedSet::from([10, 20]);
+    ///
+    /// assert!(sorted_set.remove(&10));
+    /// assert!(!sorted_set.remove(&10));
+    /// ```
+    pub fn remove<Q>(&mut self, element: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._list.discard(element)
+    }
+
+    /// Removes the element equal to `element` from the SortedSet and returns it, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let mut sorted_set = SortedSet::from([10, 20]);
+    ///
+    /// assert_eq!(Some(10), sorted_set.take(&10));
+    /// assert_eq!(None, sorted_set.take(&10));
+    /// ```
+    pub fn take<Q>(&mut self, element: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._list.remove_value(element)
+    }
+
+    /// Returns whether the SortedSet contains `element`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set = SortedSet::from([10, 20]);
+    ///
+    /// assert!(sorted_set.contains(&10));
+    /// assert!(!sorted_set.contains(&15));
+    /// ```
+    pub fn contains<Q>(&self, element: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._list.contains(element)
+    }
+
+    /// Returns the k-th smallest (0-indexed) element of the SortedSet, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set = SortedSet::from([30, 10, 20]);
+    ///
+    /// assert_eq!(Some(&20), sorted_set.kth(1));
+    /// assert_eq!(None, sorted_set.kth(3));
+    /// ```
+    pub fn kth(&self, k: usize) -> Option<&T> {
+        self._list.get(k)
+    }
+
+    /// Returns the index of `element` in the SortedSet, if present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set = SortedSet::from([30, 10, 20]);
+    ///
+    /// assert_eq!(Some(2), sorted_set.index_of(&30));
+    /// assert_eq!(None, sorted_set.index_of(&25));
+    /// ```
+    pub fn index_of<Q>(&self, element: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._list.binary_search(element).ok()
+    }
+
+    /// Returns the number of elements smaller than `element`, i.e. its rank.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set = SortedSet::from([30, 10, 20]);
+    ///
+    /// assert_eq!(2, sorted_set.rank(&25));
+    /// ```
+    pub fn rank<Q>(&self, element: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self._list.bisect_left(element)
+    }
+
+    /// Returns the smallest element of the SortedSet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set = SortedSet::from([30, 10, 20]);
+    /// assert_eq!(Some(&10), sorted_set.first());
+    /// ```
+    pub fn first(&self) -> Option<&T> {
+        self._list.first()
+    }
+
+    /// Returns the largest element of the SortedSet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set = SortedSet::from([30, 10, 20]);
+    /// assert_eq!(Some(&30), sorted_set.last());
+    /// ```
+    pub fn last(&self) -> Option<&T> {
+        self._list.last()
+    }
+
+    /// Removes the smallest element of the SortedSet and returns it, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let mut sorted_set = SortedSet::from([30, 10, 20]);
+    /// assert_eq!(Some(10), sorted_set.pop_first());
+    /// ```
+    pub fn pop_first(&mut self) -> Option<T> {
+        self._list.pop_first()
+    }
+
+    /// Removes the largest element of the SortedSet and returns it, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let mut sorted_set = SortedSet::from([30, 10, 20]);
+    /// assert_eq!(Some(30), sorted_set.pop_last());
+    /// ```
+    pub fn pop_last(&mut self) -> Option<T> {
+        self._list.pop_last()
+    }
+
+    /// Returns the number of elements stored in the SortedSet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set = SortedSet::from([30, 10, 10]);
+    /// assert_eq!(2, sorted_set.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self._list.len()
+    }
+
+    /// Returns whether the SortedSet is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set: SortedSet<i32> = SortedSet::new();
+    /// assert!(sorted_set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self._list.is_empty()
+    }
+
+    /// Clears the SortedSet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let mut sorted_set = SortedSet::from([30, 10, 20]);
+    /// sorted_set.clear();
+    ///
+    /// assert!(sorted_set.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self._list.clear();
+    }
+
+    /// Returns an iterator over the elements of the SortedSet, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set = SortedSet::from([30, 10, 20]);
+    /// assert_eq!(vec![&10, &20, &30], sorted_set.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self._list.iter()
+    }
+
+    /// Returns an iterator over the elements within the value range `range`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let sorted_set = SortedSet::from([30, 10, 20]);
+    /// assert_eq!(vec![&10, &20], sorted_set.range(..30).collect::<Vec<_>>());
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        self._list.range(range)
+    }
+
+    /// Returns a new SortedSet with the elements present in `self` or `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let a = SortedSet::from([1, 2, 3]);
+    /// let b = SortedSet::from([2, 3, 4]);
+    /// assert_eq!(vec![&1, &2, &3, &4], a.union(&b).iter().collect::<Vec<_>>());
+    /// ```
+    pub fn union(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        self._merge(other, |in_self, in_other| in_self || in_other)
+    }
+
+    /// Returns a new SortedSet with the elements present in both `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let a = SortedSet::from([1, 2, 3]);
+    /// let b = SortedSet::from([2, 3, 4]);
+    /// assert_eq!(vec![&2, &3], a.intersection(&b).iter().collect::<Vec<_>>());
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        self._merge(other, |in_self, in_other| in_self && in_other)
+    }
+
+    /// Returns a new SortedSet with the elements present in `self` but not in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let a = SortedSet::from([1, 2, 3]);
+    /// let b = SortedSet::from([2, 3, 4]);
+    /// assert_eq!(vec![&1], a.difference(&b).iter().collect::<Vec<_>>());
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        self._merge(other, |in_self, in_other| in_self && !in_other)
+    }
+
+    /// Returns a new SortedSet with the elements present in exactly one of `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let a = SortedSet::from([1, 2, 3]);
+    /// let b = SortedSet::from([2, 3, 4]);
+    /// assert_eq!(vec![&1, &4], a.symmetric_difference(&b).iter().collect::<Vec<_>>());
+    /// ```
+    pub fn symmetric_difference(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        self._merge(other, |in_self, in_other| in_self != in_other)
+    }
+
+    /// Returns whether every element of `self` is also in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let a = SortedSet::from([1, 2]);
+    /// let b = SortedSet::from([1, 2, 3]);
+    ///
+    /// assert!(a.is_subset(&b));
+    /// assert!(!b.is_subset(&a));
+    /// ```
+    pub fn is_subset(&self, other: &Self) -> bool {
+        if self.len() > other.len() {
+            return false;
+        }
+        MergeWalk::new(self.iter(), other.iter()).all(|(_, in_self, in_other)| !in_self || in_other)
+    }
+
+    /// Returns whether every element of `other` is also in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let a = SortedSet::from([1, 2, 3]);
+    /// let b = SortedSet::from([1, 2]);
+    ///
+    /// assert!(a.is_superset(&b));
+    /// ```
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns whether `self` and `other` have no element in common.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let a = SortedSet::from([1, 2]);
+    ///
+    /// assert!(a.is_disjoint(&SortedSet::from([3, 4])));
+    /// assert!(!a.is_disjoint(&SortedSet::from([2, 3])));
+    /// ```
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        MergeWalk::new(self.iter(), other.iter())
+            .all(|(_, in_self, in_other)| !(in_self && in_other))
+    }
+}
+
+/// Walks two sorted iterators of distinct elements in lockstep, yielding every distinct element once
+/// together with whether it appears in the first and in the second iterator.
+struct MergeWalk<'a, T> {
+    _a: Peekable<Iter<'a, T>>,
+    _b: Peekable<Iter<'a, T>>,
+}
+
+impl<'a, T> MergeWalk<'a, T>
+where
+    T: Ord,
+{
+    fn new(a: Iter<'a, T>, b: Iter<'a, T>) -> Self {
+        Self {
+            _a: a.peekable(),
+            _b: b.peekable(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for MergeWalk<'a, T>
+where
+    T: Ord,
+{
+    type Item = (&'a T, bool, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordering = match (self._a.peek(), self._b.peek()) {
+            (Some(x), Some(y)) => x.cmp(y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return None,
+        };
+
+        match ordering {
+            Ordering::Less => Some((self._a.next().unwrap(), true, false)),
+            Ordering::Greater => Some((self._b.next().unwrap(), false, true)),
+            Ordering::Equal => {
+                self._b.next();
+                Some((self._a.next().unwrap(), true, true))
+            }
+        }
+    }
+}
+
+impl<T> Default for SortedSet<T>
+where
+    T: Ord,
+{
+    /// Creates an empty SortedSet.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<usize> for SortedSet<T>
+where
+    T: Ord,
+{
+    type Output = T;
+
+    /// Access the SortedSet for the given index.
+    fn index(&self, index: usize) -> &Self::Output {
+        self._list.kth_smallest(index)
+    }
+}
+
+impl<T> From<Vec<T>> for SortedSet<T>
+where
+    T: Ord,
+{
+    /// Creates a SortedSet from a Vec, dropping duplicated elements.
+    fn from(array: Vec<T>) -> Self {
+        let mut sorted_list = SortedList::from(array);
+        sorted_list.dedup();
+        Self { _list: sorted_list }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SortedSet<T>
+where
+    T: Ord,
+{
+    /// Creates a SortedSet from an array, dropping duplicated elements.
+    fn from(array: [T; N]) -> Self {
+        Self::from(Vec::from(array))
+    }
+}
+
+impl<T> FromIterator<T> for SortedSet<T>
+where
+    T: Ord,
+{
+    /// Creates a SortedSet from an iterator, dropping duplicated elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T> Extend<T> for SortedSet<T>
+where
+    T: Ord,
+{
+    /// Inserts a batch of elements, skipping the ones which are already present.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sortedlist_rs::SortedSet;
+    ///
+    /// let mut sorted_set = SortedSet::from([1, 2]);
+    /// sorted_set.extend([2, 3, 3]);
+    ///
+    /// assert_eq!(3, sorted_set.len());
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut batch: Vec<T> = iter.into_iter().collect();
+        batch.sort();
+        batch.dedup();
+
+        // the batch is sorted, so the list which may hold an element equal to the next one never moves back
+        let lists = &self._list._lists;
+        let mut i = 0;
+        batch.retain(|element| {
+            i += lists[i..].partition_point(|list| list.last().is_some_and(|last| last < element));
+            lists
+                .get(i)
+                .is_none_or(|list| list.binary_search(element).is_err())
+        });
+        self._list.extend(batch);
+    }
+}
+
+impl<T> IntoIterator for SortedSet<T>
+where
+    T: Ord,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self._list.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SortedSet<T>
+where
+    T: Ord,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> fmt::Debug for SortedSet<T>
+where
+    T: Ord + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use rand::{thread_rng, Rng};
+
+    use crate::SortedSet;
+
+    fn random_set(size: usize, max: u32) -> (SortedSet<u32>, BTreeSet<u32>) {
+        let mut rng = thread_rng();
+        let array: Vec<u32> = (0..size).map(|_| rng.gen_range(0..max)).collect();
+        (SortedSet::from(array.clone()), BTreeSet::from_iter(array))
+    }
+
+    #[test]
+    fn random_tests() {
+        // arrange
+        let mut rng = thread_rng();
+        let (mut sorted_set, mut expected) = random_set(10_000, 20_000);
+
+        for _ in 0..10_000 {
+            // act & assert
+            let x = rng.gen_range(0..20_000);
+            match rng.gen::<bool>() {
+                true => assert_eq!(expected.insert(x), sorted_set.insert(x)),
+                false => assert_eq!(expected.remove(&x), sorted_set.remove(&x)),
+            }
+
+            let k = rng.gen_range(0..expected.len() + 10);
+            assert_eq!(expected.iter().nth(k), sorted_set.kth(k));
+            let index = expected.contains(&x).then(|| expected.range(..x).count());
+            assert_eq!(index, sorted_set.index_of(&x));
+        }
+
+        assert_eq!(expected.len(), sorted_set.len());
+        assert!(expected.iter().eq(sorted_set.iter()));
+    }
+
+    #[test]
+    fn set_algebra_test() {
+        for (size, max) in [(0, 10), (10, 10), (5_000, 10_000), (20_000, 15_000)] {
+            // arrange
+            let (a, expected_a) = random_set(size, max);
+            let (b, expected_b) = random_set(10_000, 10_000);
+
+            // assert
+            assert!(expected_a.union(&expected_b).eq(a.union(&b).iter()));
+            assert!(expected_a
+                .intersection(&expected_b)
+                .eq(a.intersection(&b).iter()));
+            assert!(expected_a
+                .difference(&expected_b)
+                .eq(a.difference(&b).iter()));
+            assert!(expected_a
+                .symmetric_difference(&expected_b)
+                .eq(a.symmetric_difference(&b).iter()));
+            assert_eq!(expected_a.is_subset(&expected_b), a.is_subset(&b));
+            assert_eq!(expected_a.is_disjoint(&expected_b), a.is_disjoint(&b));
+
+            let intersection = a.intersection(&b);
+            assert!(intersection.is_subset(&a));
+            assert!(b.is_superset(&intersection));
+            assert!(a.difference(&b).is_disjoint(&b));
+        }
+    }
+
+    #[test]
+    fn extend_test() {
+        let mut sorted_set: SortedSet<u32> = (0..5_000).collect();
+        sorted_set.extend(2_500..7_500);
+        sorted_set.extend([0, 0, 7_500]);
+
+        assert!(sorted_set.iter().copied().eq(0..7_501));
+
+        let (mut sorted_set, mut expected) = random_set(10_000, 20_000);
+        let (batch, _) = random_set(5_000, 40_000);
+        sorted_set.extend(batch.iter().copied());
+        expected.extend(batch.iter().copied());
+        assert!(expected.iter().eq(sorted_set.iter()));
+    }
+}